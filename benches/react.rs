@@ -0,0 +1,69 @@
+//! Regression baseline for `react_once` and `react_until_done` on representative mixtures.
+//!
+//! Record a baseline with `cargo bench --bench react -- --save-baseline main`, then compare a change
+//! against it with `cargo bench --bench react -- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+use tg_atmos_sim::constants as C;
+use tg_atmos_sim::reactions as R;
+use tg_atmos_sim::{gen_gas_mix_with_temp, temperature, Gas, GasMixture};
+
+fn scenarios() -> Vec<(&'static str, GasMixture)> {
+    vec![
+        ("inert air", GasMixture::standard_air()),
+        (
+            "plasma fire",
+            GasMixture::standard_air()
+                .with_gas(Gas::Pl, 50.0)
+                .clone_at_temperature(1000.0),
+        ),
+        (
+            "fusion",
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => 500.0,
+                    Gas::H2 => 500.0,
+                    Gas::CO2 => 500.0,
+                )
+                at(temperature!(20000.0, K))
+                in(C::CELL_VOLUME)
+            ),
+        ),
+        (
+            "full chain",
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::N2O => 50.0,
+                    Gas::H2 => 50.0,
+                    Gas::Pl => 50.0,
+                    Gas::O2 => 500.0,
+                    Gas::N2 => 500.0,
+                    Gas::NO2 => 50.0,
+                    Gas::ST => 50.0,
+                )
+                at(temperature!(1e5, K))
+                in(C::CELL_VOLUME)
+            ),
+        ),
+    ]
+}
+
+fn react_once(c: &mut Criterion) {
+    let mut group = c.benchmark_group("react_once");
+    for (name, gm) in scenarios() {
+        group.bench_function(name, |b| b.iter(|| R::react_once(black_box(gm))));
+    }
+    group.finish();
+}
+
+fn react_until_done(c: &mut Criterion) {
+    let mut group = c.benchmark_group("react_until_done");
+    for (name, gm) in scenarios() {
+        group.bench_function(name, |b| b.iter(|| R::react_until_done(black_box(gm))));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, react_once, react_until_done);
+criterion_main!(benches);
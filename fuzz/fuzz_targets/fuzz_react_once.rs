@@ -0,0 +1,38 @@
+//! Checks the contract documented on `react_once`: any mixture inside its bounds reacts without panicking
+//! into a mixture that is still inside them. Run with `cargo +nightly fuzz run fuzz_react_once`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use tg_atmos_sim::constants as C;
+use tg_atmos_sim::gas::ALL_GASES;
+use tg_atmos_sim::reactions as R;
+use tg_atmos_sim::{gen_gas_vec, GasMixture};
+
+const MAX_MOLES: f64 = 1e8;
+const MAX_TEMPERATURE: f64 = 1e10;
+const MAX_VOLUME: f64 = 1e9;
+
+fuzz_target!(|input: (Vec<f64>, f64, f64)| {
+    let (amounts, temperature, volume) = input;
+
+    let in_bounds = amounts.iter().all(|amount| (0.0..=MAX_MOLES).contains(amount))
+        && (C::TCMB..=MAX_TEMPERATURE).contains(&temperature)
+        && volume > 0.0
+        && volume <= MAX_VOLUME;
+    if !in_bounds {
+        return;
+    }
+
+    let mut gases = gen_gas_vec!();
+    for (gas, amount) in ALL_GASES.iter().zip(amounts) {
+        gases[*gas] = amount;
+    }
+    let gm = GasMixture::new(gases, temperature, volume);
+
+    let result = R::react_once(gm);
+    let physical = result.temperature().is_finite()
+        && result.temperature() >= C::TCMB
+        && result.gases.0.values().all(|amount| amount.is_finite() && *amount >= 0.0);
+    assert!(physical, "{:?} reacted into {:?}", gm, result);
+});
@@ -0,0 +1,47 @@
+use crate::{constants as C, gen_gas_vec, reactions as R, Gas, GasMixture};
+
+/// Estimate the mixture `react_until_done` converges to for a plasma fire, solving for its end state directly instead of ticking.
+///
+/// Only plasma fires whose burn has a closed form are handled, returning `None` for every other mixture:
+/// - stable mixtures, which are their own end state;
+/// - oxygen-starved plasma fires above `PLASMA_UPPER_TEMPERATURE`, where plasma_fire burns oxygen at a fixed ratio
+///   until it runs out, no other reaction's gate is open, none can open on the way and the fire never becomes saturated.
+///
+/// Ticking stops once less than `MINIMUM_MOLE_COUNT` oxygen is left, so the actual result can keep up to that much
+/// oxygen, and the matching plasma and heat, that the estimate burns.
+pub fn plasma_fire_equilibrium(gm: &GasMixture) -> Option<GasMixture> {
+    if gm.is_stable() {
+        return Some(*gm);
+    }
+
+    let pl = gm[Gas::Pl];
+    let o2 = gm[Gas::O2];
+    // temp_scale is pinned at 1 above PLASMA_UPPER_TEMPERATURE, and the fire only gets hotter
+    let oxygen_burn_rate = C::OXYGEN_BURN_RATE_BASE - 1.;
+    let burnt = o2 / oxygen_burn_rate;
+    let oxygen_starved = o2 > 0.0 && burnt <= pl;
+    if gm.temperature() < C::PLASMA_UPPER_TEMPERATURE || !oxygen_starved {
+        return None;
+    }
+
+    // Every other reaction must stay shut before and after: its gate can only open through the gases a fire touches
+    let only_plasma_fire = R::STANDARD_REACTION_SPECS
+        .iter()
+        .filter(|spec| spec.can_react(gm))
+        .all(|spec| spec.name == R::plasma_fire::SPEC.name);
+    if !only_plasma_fire {
+        return None;
+    }
+
+    let burnt_out = GasMixture::with_energy(
+        gm.gases + gen_gas_vec!(Gas::Pl => -burnt, Gas::O2 => -o2, Gas::CO2 => burnt,),
+        gm.get_energy() + burnt * C::FIRE_PLASMA_ENERGY_RELEASED,
+        gm.volume,
+    );
+
+    if burnt_out.is_stable() {
+        Some(burnt_out)
+    } else {
+        None
+    }
+}
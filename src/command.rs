@@ -0,0 +1,45 @@
+use crate::{reactions as R, Gas, GasMixture};
+
+/// Apply one console command to `gm`, for interactive atmos calculators:
+///
+/// - `react` or `react <ticks>`: react once, or that many times.
+/// - `heat <joules>`: add thermal energy, negative to remove it.
+/// - `add <gas> <moles>`: add gas at the mixture's temperature, named as `Gas::from_name` accepts.
+/// - `set_temp <kelvin>`: set the temperature, floored at TCMB.
+///
+/// Unknown commands and malformed arguments are reported as an error message.
+pub fn apply_command(gm: GasMixture, cmd: &str) -> Result<GasMixture, String> {
+    let mut words = cmd.split_whitespace();
+    let verb = words.next().ok_or_else(|| "empty command".to_string())?;
+    let args: Vec<&str> = words.collect();
+
+    match (verb, args.as_slice()) {
+        ("react", []) => Ok(R::react_once(gm)),
+        ("react", [ticks]) => {
+            let ticks: usize = parse(ticks, "tick count")?;
+            Ok(R::react_several(gm, ticks).last().copied().unwrap_or(gm))
+        }
+        ("heat", [joules]) => {
+            let joules = parse(joules, "energy")?;
+            if gm.get_heat_cap() == 0.0 {
+                return Err("cannot heat a mixture without heat capacity".to_string());
+            }
+            Ok(gm.adjust_thermal_energy(joules))
+        }
+        ("add", [gas, moles]) => {
+            let gas = Gas::from_name(gas).map_err(|e| e.to_string())?;
+            Ok(gm.with_gas(gas, parse(moles, "amount")?))
+        }
+        ("set_temp", [kelvin]) => Ok(gm.clone_at_temperature(parse(kelvin, "temperature")?)),
+        ("react", _) | ("heat", _) | ("add", _) | ("set_temp", _) => {
+            Err(format!("wrong number of arguments to {}", verb))
+        }
+        _ => Err(format!("unknown command: {}", verb)),
+    }
+}
+
+fn parse<T: std::str::FromStr>(token: &str, what: &str) -> Result<T, String> {
+    token
+        .parse()
+        .map_err(|_| format!("invalid {}: {:?}", what, token))
+}
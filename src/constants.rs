@@ -2,6 +2,10 @@
 pub const ONE_ATMOSPHERE: f64 = 101.325;
 /// cbindgen:ignore
 pub const R_IDEAL_GAS_EQUATION: f64 = 8.31;
+/// The gas constant in P = nRT/V, in kPa L / (mol K) so that pressures come out in kPa for volumes in litres.
+/// Rounded to 8.31 as in the game rather than the physical 8.314.
+/// cbindgen:ignore
+pub const IDEAL_GAS_CONSTANT: f64 = R_IDEAL_GAS_EQUATION;
 /// cbindgen:ignore
 pub const TCMB: f64 = 2.7;
 /// cbindgen:ignore
@@ -10,6 +14,12 @@ pub const T0C: f64 = 273.15;
 pub const T20C: f64 = 293.15;
 /// cbindgen:ignore
 pub const CELL_VOLUME: f64 = 2500.;
+/// cbindgen:ignore
+pub const MOLES_CELLSTANDARD: f64 = ONE_ATMOSPHERE * CELL_VOLUME / (T20C * R_IDEAL_GAS_EQUATION);
+/// cbindgen:ignore
+pub const O2STANDARD: f64 = 0.21;
+/// cbindgen:ignore
+pub const N2STANDARD: f64 = 0.79;
 
 /// cbindgen:ignore
 pub const FIRE_MINIMUM_TEMPERATURE_TO_SPREAD: f64 = 150.0 + T0C;
@@ -20,6 +30,18 @@ pub const FIRE_SPREAD_RADIOSITY_SCALE: f64 = 0.85;
 /// cbindgen:ignore
 pub const FIRE_GROWTH_RATE: f64 = 40000.0;
 /// cbindgen:ignore
+pub const TRITIUM_MINIMUM_BURN_TEMPERATURE: f64 = FIRE_MINIMUM_TEMPERATURE_TO_EXIST;
+/// cbindgen:ignore
+pub const NITRYL_FORMATION_MIN_TEMPERATURE: f64 = FIRE_MINIMUM_TEMPERATURE_TO_EXIST * 60.;
+/// cbindgen:ignore
+pub const STIMULUM_FORMATION_MIN_TEMPERATURE: f64 = STIMULUM_HEAT_SCALE / 2.;
+/// cbindgen:ignore
+pub const NOBLIUM_FORMATION_MIN_TEMPERATURE: f64 = 5e6;
+/// cbindgen:ignore
+pub const NOBLIUM_DECOMPOSITION_MIN_TEMPERATURE: f64 = 1e7;
+/// cbindgen:ignore
+pub const NOBLIUM_DECOMPOSITION_RATE: f64 = 0.01;
+/// cbindgen:ignore
 pub const PLASMA_MINIMUM_BURN_TEMPERATURE: f64 = 100.0 + T0C;
 /// cbindgen:ignore
 pub const PLASMA_UPPER_TEMPERATURE: f64 = 1370.0 + T0C;
@@ -80,6 +102,12 @@ pub const STIMULUM_SECOND_RISE: f64 = 0.0009;
 /// cbindgen:ignore
 pub const STIMULUM_ABSOLUTE_DROP: f64 = 0.00000335;
 /// cbindgen:ignore
+pub const STIMULUM_DECAY_MIN_TEMPERATURE: f64 = 1000.;
+/// cbindgen:ignore
+pub const STIMULUM_DECAY_RATE: f64 = 0.05;
+/// cbindgen:ignore
+pub const STIMULUM_DECAY_ENERGY_RELEASED: f64 = 10000.;
+/// cbindgen:ignore
 pub const REACTION_OPPRESSION_THRESHOLD: f64 = 5.;
 /// cbindgen:ignore
 pub const NOBLIUM_FORMATION_ENERGY: f64 = 2e9;
@@ -131,3 +159,72 @@ pub const FUSION_RAD_MIDPOINT: f64 = 15.;
 pub const FUSION_MIDDLE_ENERGY_REFERENCE: f64 = 1e6;
 /// cbindgen:ignore
 pub const FUSION_BUFFER_DIVISOR: f64 = 1.;
+/// cbindgen:ignore
+pub const N2O_DROWSY_PARTIAL_PRESSURE: f64 = 1.;
+/// cbindgen:ignore
+pub const N2O_SLEEP_PARTIAL_PRESSURE: f64 = 5.;
+/// cbindgen:ignore
+pub const FINGERPRINT_MOLE_QUANTUM: f64 = 1e-4;
+/// cbindgen:ignore
+pub const FINGERPRINT_TEMPERATURE_QUANTUM: f64 = 1e-2;
+/// cbindgen:ignore
+pub const FINGERPRINT_VOLUME_QUANTUM: f64 = 1e-2;
+/// cbindgen:ignore
+pub const LABEL_GAS_COUNT: usize = 3;
+/// cbindgen:ignore
+pub const HAZARD_TOXIC_PARTIAL_PRESSURE: f64 = 0.5;
+/// cbindgen:ignore
+pub const HAZARD_LOW_PRESSURE: f64 = 20.;
+/// cbindgen:ignore
+pub const HAZARD_HIGH_PRESSURE: f64 = 550.;
+/// cbindgen:ignore
+pub const HAZARD_LOW_TEMPERATURE: f64 = T0C;
+/// cbindgen:ignore
+pub const HAZARD_HIGH_TEMPERATURE: f64 = T0C + 66.;
+/// cbindgen:ignore
+pub const HAZARD_O2_PARTIAL_PRESSURE: f64 = 140.;
+/// cbindgen:ignore
+pub const HAZARD_CO2_PARTIAL_PRESSURE: f64 = 10.;
+/// Seconds between atmospherics ticks, SSair's wait.
+/// cbindgen:ignore
+pub const ATMOS_TICK_SECONDS: f64 = 0.5;
+/// cbindgen:ignore
+pub const GAS_ROUNDING_TOLERANCE: f64 = 1e-9;
+/// Smallest volume, in litres, used when computing pressures, so volumeless mixtures get a large but finite pressure.
+/// cbindgen:ignore
+pub const MINIMUM_VOLUME: f64 = 1e-3;
+/// Moles of hyper-noblium at which it suppresses all other reactions.
+/// cbindgen:ignore
+pub const NOBLIUM_SUPPRESSION_THRESHOLD: f64 = 5.;
+/// Pressure at which a gas tank ruptures, TANK_FRAGMENT_PRESSURE.
+/// cbindgen:ignore
+pub const TANK_FRAGMENT_PRESSURE: f64 = 40. * ONE_ATMOSPHERE;
+/// Relative change per tick below which `react_until_done` considers a mixture settled.
+/// cbindgen:ignore
+pub const REACTION_SETTLE_TOLERANCE: f64 = 1e-12;
+/// Kelvin below its temperature gate that a reaction which fired on the previous tick keeps firing,
+/// by default, when reacting with a `ReactionContext`.
+/// cbindgen:ignore
+pub const REACTION_HYSTERESIS_BAND: f64 = 5.;
+/// Ticks after which `react_each_until_done_stats` gives up on a mixture that has not settled.
+/// cbindgen:ignore
+pub const BATCH_MAX_TICKS: usize = 10_000;
+/// Moles at which most visible gases start to show as an overlay, MOLES_GAS_VISIBLE.
+/// cbindgen:ignore
+pub const MOLES_GAS_VISIBLE: f64 = 0.25;
+/// Nitrous oxide is fainter and needs twice as much to show.
+/// cbindgen:ignore
+pub const MOLES_N2O_VISIBLE: f64 = MOLES_GAS_VISIBLE * 2.;
+/// How many times its visible amount a gas needs to be fully opaque, FACTOR_GAS_VISIBLE_MAX.
+/// cbindgen:ignore
+pub const FACTOR_GAS_VISIBLE_MAX: f64 = 20.;
+/// cbindgen:ignore
+pub const MOLES_PLASMA_OPAQUE: f64 = MOLES_GAS_VISIBLE * FACTOR_GAS_VISIBLE_MAX;
+/// cbindgen:ignore
+pub const MOLES_H2O_OPAQUE: f64 = MOLES_GAS_VISIBLE * FACTOR_GAS_VISIBLE_MAX;
+/// cbindgen:ignore
+pub const MOLES_N2O_OPAQUE: f64 = MOLES_N2O_VISIBLE * FACTOR_GAS_VISIBLE_MAX;
+/// cbindgen:ignore
+pub const MOLES_NO2_OPAQUE: f64 = MOLES_GAS_VISIBLE * FACTOR_GAS_VISIBLE_MAX;
+/// cbindgen:ignore
+pub const MOLES_TRITIUM_OPAQUE: f64 = MOLES_GAS_VISIBLE * FACTOR_GAS_VISIBLE_MAX;
@@ -17,7 +17,7 @@ impl From<GasMixture> for GasMixtureFFI {
     fn from(source: GasMixture) -> Self {
         GasMixtureFFI {
             gases: source.gases.0.as_slice().try_into().unwrap(),
-            temperature: source.temperature,
+            temperature: source.temperature(),
             volume: source.volume,
         }
     }
@@ -25,11 +25,11 @@ impl From<GasMixture> for GasMixtureFFI {
 
 impl Into<GasMixture> for GasMixtureFFI {
     fn into(self) -> GasMixture {
-        GasMixture {
-            gases: GasVec(GasEnumMap::from(|gas: Gas| self.gases[gas as usize])),
-            temperature: self.temperature,
-            volume: self.volume,
-        }
+        GasMixture::new(
+            GasVec(GasEnumMap::from(|gas: Gas| self.gases[gas as usize])),
+            self.temperature,
+            self.volume,
+        )
     }
 }
 
@@ -1,9 +1,11 @@
 extern crate enum_map;
 
+use crate::constants as C;
 use enum_map as EM;
-use std::ops::{Add, Index, Mul};
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
 
-#[derive(Copy, Clone, Debug, EM::Enum)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, EM::Enum)]
 #[repr(u8)]
 pub enum Gas {
     N2,
@@ -20,9 +22,98 @@ pub enum Gas {
     PlOx,
 }
 pub const GAS_AMT: usize = 12;
+/// Number of gases, for arrays indexed in `ALL_GASES` order.
+/// cbindgen:ignore
+pub const GAS_COUNT: usize = GAS_AMT;
+
+/// cbindgen:ignore
+pub const ALL_GASES: [Gas; GAS_AMT] = [
+    Gas::N2,
+    Gas::O2,
+    Gas::CO2,
+    Gas::N2O,
+    Gas::Pl,
+    Gas::H2O,
+    Gas::HNb,
+    Gas::NO2,
+    Gas::H2,
+    Gas::BZ,
+    Gas::ST,
+    Gas::PlOx,
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub token: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unrecognized gas name: {:?}", self.token)
+    }
+}
+
+impl std::error::Error for ParseError {}
 
 impl Gas {
-    fn heat_cap_of(self) -> f64 {
+    /// Parse either the short ("O2") or the long ("oxygen") name of a gas.
+    /// Matching ignores case as well as spaces, underscores and hyphens.
+    pub fn from_name(s: &str) -> Result<Gas, ParseError> {
+        fn normalize(name: &str) -> String {
+            name.chars()
+                .filter(|c| !matches!(c, ' ' | '_' | '-'))
+                .flat_map(char::to_lowercase)
+                .collect()
+        }
+
+        let token = normalize(s);
+        ALL_GASES
+            .iter()
+            .copied()
+            .find(|gas| {
+                normalize(gas.short_name()) == token || normalize(gas.canonical_name()) == token
+            })
+            .ok_or_else(|| ParseError {
+                token: s.to_string(),
+            })
+    }
+
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Gas::N2 => "N2",
+            Gas::O2 => "O2",
+            Gas::CO2 => "CO2",
+            Gas::N2O => "N2O",
+            Gas::Pl => "Pl",
+            Gas::H2O => "H2O",
+            Gas::HNb => "HNb",
+            Gas::NO2 => "NO2",
+            Gas::H2 => "H2",
+            Gas::BZ => "BZ",
+            Gas::ST => "ST",
+            Gas::PlOx => "PlOx",
+        }
+    }
+
+    pub fn canonical_name(self) -> &'static str {
+        match self {
+            Gas::N2 => "nitrogen",
+            Gas::O2 => "oxygen",
+            Gas::CO2 => "carbon dioxide",
+            Gas::N2O => "nitrous oxide",
+            Gas::Pl => "plasma",
+            Gas::H2O => "water vapor",
+            Gas::HNb => "hyper-noblium",
+            Gas::NO2 => "nitryl",
+            Gas::H2 => "tritium",
+            Gas::BZ => "bz",
+            Gas::ST => "stimulum",
+            Gas::PlOx => "pluoxium",
+        }
+    }
+
+    /// Heat capacity per mole.
+    pub fn specific_heat(self) -> f64 {
         match self {
             Gas::N2 => 20.,
             Gas::O2 => 20.,
@@ -39,7 +130,38 @@ impl Gas {
         }
     }
 
-    fn fusion_power_of(self) -> f64 {
+    /// Molar mass in g/mol. Values for the exotic gases are rough estimates.
+    pub fn molar_mass(self) -> f64 {
+        match self {
+            Gas::N2 => 28.014,
+            Gas::O2 => 31.998,
+            Gas::CO2 => 44.009,
+            Gas::N2O => 44.013,
+            Gas::Pl => 180.,
+            Gas::H2O => 18.015,
+            Gas::HNb => 240.,
+            Gas::NO2 => 46.005,
+            Gas::H2 => 6.032,
+            Gas::BZ => 337.419,
+            Gas::ST => 150.,
+            Gas::PlOx => 212.,
+        }
+    }
+
+    /// Moles at which the gas starts to show as an overlay and at which it becomes fully opaque, or `None` if it is invisible.
+    pub fn visibility_thresholds(self) -> Option<(f64, f64)> {
+        match self {
+            Gas::Pl => Some((C::MOLES_GAS_VISIBLE, C::MOLES_PLASMA_OPAQUE)),
+            Gas::H2O => Some((C::MOLES_GAS_VISIBLE, C::MOLES_H2O_OPAQUE)),
+            Gas::N2O => Some((C::MOLES_N2O_VISIBLE, C::MOLES_N2O_OPAQUE)),
+            Gas::NO2 => Some((C::MOLES_GAS_VISIBLE, C::MOLES_NO2_OPAQUE)),
+            Gas::H2 => Some((C::MOLES_GAS_VISIBLE, C::MOLES_TRITIUM_OPAQUE)),
+            _ => None,
+        }
+    }
+
+    /// Contribution of one mole of the gas to the gas power of a fusion reaction; pluoxium damps it.
+    pub fn fusion_power(self) -> f64 {
         match self {
             Gas::N2O => 10.,
             Gas::H2O => 8.,
@@ -60,23 +182,53 @@ pub type GasEnumMap = EM::EnumMap<Gas, f64>;
 pub struct GasVec(pub GasEnumMap);
 
 impl GasVec {
+    pub fn single(gas: Gas, amount: f64) -> Self {
+        GasVec(GasEnumMap::from(|g| if g == gas { amount } else { 0.0 }))
+    }
+
     pub fn get_heat_cap(&self) -> f64 {
         self.0
             .iter()
-            .map(|(g, a)| a * Gas::heat_cap_of(g))
+            .map(|(g, a)| a * Gas::specific_heat(g))
             .sum::<f64>()
     }
 
+    /// Sum over the gases of their amount times `Gas::fusion_power`.
     pub fn get_fusion_power(&self) -> f64 {
         self.0
             .iter()
-            .map(|(g, a)| a * Gas::fusion_power_of(g))
+            .map(|(g, a)| a * g.fusion_power())
             .sum::<f64>()
     }
 
     pub fn get_total_amount(&self) -> f64 {
         self.0.values().sum()
     }
+
+    /// Set to zero the amounts that are negative only through floating point rounding, given the amounts they were computed from.
+    /// Larger negative amounts, which mean more gas was used than there was, are kept so they can be noticed.
+    pub fn without_rounding_negatives(self, before: &GasVec) -> Self {
+        GasVec(GasEnumMap::from(|g| {
+            let tolerance = C::GAS_ROUNDING_TOLERANCE * before.0[g].abs().max(1.0);
+            if self.0[g] < 0.0 && self.0[g] >= -tolerance {
+                0.0
+            } else {
+                self.0[g]
+            }
+        }))
+    }
+
+    /// Total moles, the same as `get_total_amount`.
+    pub fn total(&self) -> f64 {
+        self.get_total_amount()
+    }
+
+    pub fn get_total_mass(&self) -> f64 {
+        self.0
+            .iter()
+            .map(|(g, a)| a * Gas::molar_mass(g))
+            .sum::<f64>()
+    }
 }
 
 impl Add<GasVec> for GasVec {
@@ -86,6 +238,13 @@ impl Add<GasVec> for GasVec {
     }
 }
 
+impl Sub<GasVec> for GasVec {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        GasVec(GasEnumMap::from(|g| self.0[g] - rhs.0[g]))
+    }
+}
+
 impl Mul<f64> for GasVec {
     type Output = Self;
 
@@ -101,3 +260,9 @@ impl Index<Gas> for GasVec {
         &self.0[gas]
     }
 }
+
+impl IndexMut<Gas> for GasVec {
+    fn index_mut(&mut self, gas: Gas) -> &mut f64 {
+        &mut self.0[gas]
+    }
+}
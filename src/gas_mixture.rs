@@ -1,35 +1,448 @@
 extern crate enum_map;
 
-use crate::{constants as C, gen_gas_vec};
+use crate::{constants as C, gen_gas_mix_with_temp, gen_gas_vec, reactions as R};
 use crate::gas::*;
-use std::{ops::{Add, Index}};
+use std::{cmp::Ordering, fmt, ops::{Add, AddAssign, Index}};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ArithmeticError {
+    NonFiniteAmount(Gas),
+    NonFiniteTemperature,
+    NonFiniteVolume,
+    NegativeAmount(Gas),
+}
+
+impl fmt::Display for ArithmeticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArithmeticError::NonFiniteAmount(gas) => write!(f, "amount of {:?} is not finite", gas),
+            ArithmeticError::NonFiniteTemperature => write!(f, "temperature is not finite"),
+            ArithmeticError::NonFiniteVolume => write!(f, "volume is not finite"),
+            ArithmeticError::NegativeAmount(gas) => write!(f, "amount of {:?} is negative", gas),
+        }
+    }
+}
+
+impl std::error::Error for ArithmeticError {}
+
+/// Plain snapshot of a mixture for presentation layers, listing only the gases present at `MINIMUM_MOLE_COUNT`.
+/// Trace amounts below it are left out of `gases` but still count towards `total_moles` and `pressure`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Summary {
+    pub gases: Vec<(Gas, f64)>,
+    pub temperature: f64,
+    pub pressure: f64,
+    pub volume: f64,
+    pub total_moles: f64,
+}
+
+/// Effect of the N2O partial pressure of a mixture on anyone breathing it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SleepLevel {
+    None,
+    Drowsy,
+    Sleeping,
+}
+
+/// A condition that would set off an air alarm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Hazard {
+    LowPressure,
+    HighPressure,
+    LowTemperature,
+    HighTemperature,
+    ExcessGas(Gas),
+}
+
+/// One step of a `GasMixture::scrub_plan`, in moles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ScrubAction {
+    /// The gas is at or above its target, and this much has to be scrubbed out.
+    Remove(Gas, f64),
+    /// The gas is below its target, and this much has to be added instead.
+    Add(Gas, f64),
+}
+
+/// Everything a dashboard shows about one tick of a mixture, gathered by `GasMixture::tick_report`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TickReport {
+    pub result: GasMixture,
+    /// Reactions that changed the mixture, in the order they ran.
+    pub reactions_fired: Vec<&'static str>,
+    /// Net thermal energy released, negative if the tick was endothermic.
+    pub energy_released: f64,
+    pub radiation: f64,
+    pub events: Vec<R::ReactionEvent>,
+}
+
+/// Limits beyond which an air alarm considers a mixture dangerous, with pressures in kPa.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AlarmThresholds {
+    pub min_pressure: f64,
+    pub max_pressure: f64,
+    pub min_temperature: f64,
+    pub max_temperature: f64,
+    pub max_partial_pressure: GasEnumMap,
+}
+
+impl Default for AlarmThresholds {
+    /// The danger levels of a standard station air alarm.
+    fn default() -> Self {
+        AlarmThresholds {
+            min_pressure: C::HAZARD_LOW_PRESSURE,
+            max_pressure: C::HAZARD_HIGH_PRESSURE,
+            min_temperature: C::HAZARD_LOW_TEMPERATURE,
+            max_temperature: C::HAZARD_HIGH_TEMPERATURE,
+            max_partial_pressure: GasEnumMap::from(|gas| match gas {
+                Gas::O2 => C::HAZARD_O2_PARTIAL_PRESSURE,
+                Gas::CO2 => C::HAZARD_CO2_PARTIAL_PRESSURE,
+                Gas::Pl | Gas::N2O => C::HAZARD_TOXIC_PARTIAL_PRESSURE,
+                _ => f64::INFINITY,
+            }),
+        }
+    }
+}
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct GasMixture {
     pub gases: GasVec,
-    pub temperature: f64,
+    /// Read with `temperature()` and write with `set_temperature`, so the representation can change without breaking callers.
+    pub(crate) temperature: f64,
     pub volume: f64,
 }
 
 impl GasMixture {
+    /// Version of the serialized mixture format, bumped whenever a change needs older data to be migrated.
+    /// Version 2 added pluoxium; see `serialization` for how older versions are read.
+    pub const SERIAL_VERSION: u32 = 2;
+
+    /// Temperature in kelvin.
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
     pub fn get_heat_cap(&self) -> f64 {
         self.gases.get_heat_cap()
     }
 
+    /// Gas power fusion uses to compute its instability; see `GasVec::get_fusion_power`.
     pub fn get_fusion_power(&self) -> f64 {
         self.gases.get_fusion_power()
     }
 
+    /// Each gas's share of `get_fusion_power`.
+    pub fn fusion_power_by_gas(&self) -> GasEnumMap {
+        GasEnumMap::from(|gas: Gas| self[gas] * gas.fusion_power())
+    }
+
     pub fn get_energy(&self) -> f64 {
         self.get_heat_cap() * self.temperature
     }
 
+    pub fn energy_contribution(&self, gas: Gas) -> f64 {
+        self[gas] * gas.specific_heat() * self.temperature
+    }
+
+    pub fn energy_by_gas(&self) -> GasEnumMap {
+        GasEnumMap::from(|gas| self.energy_contribution(gas))
+    }
+
     pub fn get_total_amount(&self) -> f64 {
         self.gases.get_total_amount()
     }
 
+    pub fn total_mass(&self) -> f64 {
+        self.gases.get_total_mass()
+    }
+
+    pub fn average_molar_mass(&self) -> f64 {
+        let total_amount = self.get_total_amount();
+        if total_amount == 0.0 {
+            0.0
+        } else {
+            self.total_mass() / total_amount
+        }
+    }
+
+    /// Heat capacity per mole, or 0 for an empty mixture.
+    pub fn molar_heat_capacity(&self) -> f64 {
+        let total_amount = self.get_total_amount();
+        if total_amount == 0.0 {
+            0.0
+        } else {
+            self.get_heat_cap() / total_amount
+        }
+    }
+
+    /// Heat capacity per kilogram, molar masses being in g/mol, or 0 for a massless mixture.
+    pub fn specific_heat_per_mass(&self) -> f64 {
+        let total_mass = self.total_mass();
+        if total_mass == 0.0 {
+            0.0
+        } else {
+            self.get_heat_cap() / (total_mass / 1000.)
+        }
+    }
+
+    /// Whether there is at least `threshold` moles of `gas`, usually `MINIMUM_MOLE_COUNT`, rather than a trace or none.
+    pub fn gas_present(&self, gas: Gas, threshold: f64) -> bool {
+        self[gas] > 0.0 && self[gas] >= threshold
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary {
+            gases: self
+                .gases
+                .0
+                .iter()
+                .filter(|(gas, _)| self.gas_present(*gas, C::MINIMUM_MOLE_COUNT))
+                .map(|(gas, amount)| (gas, *amount))
+                .collect(),
+            temperature: self.temperature,
+            pressure: self.get_pressure(),
+            volume: self.volume,
+            total_moles: self.get_total_amount(),
+        }
+    }
+
+    /// Text rendering of `summary()` for golden snapshot tests: one `name value` line per field in a fixed order,
+    /// then one per gas present in enum order, every value rounded to `precision` decimals.
+    /// Rounding is done by the formatter rather than libm, so the output is the same on every platform.
+    pub fn snapshot_string(&self, precision: usize) -> String {
+        let fixed = |value: f64| {
+            let formatted = format!("{:.*}", precision, value);
+            // Tiny negative values round to "-0.000", which would make the snapshot depend on rounding noise
+            match formatted.strip_prefix('-') {
+                Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_string(),
+                _ => formatted,
+            }
+        };
+
+        let summary = self.summary();
+        let mut lines = vec![
+            format!("temperature {}", fixed(summary.temperature)),
+            format!("pressure {}", fixed(summary.pressure)),
+            format!("volume {}", fixed(summary.volume)),
+            format!("total_moles {}", fixed(summary.total_moles)),
+        ];
+        lines.extend(
+            summary
+                .gases
+                .iter()
+                .map(|(gas, amount)| format!("{} {}", gas.short_name(), fixed(*amount))),
+        );
+
+        lines.join("\n")
+    }
+
+    /// Pressure in kPa by the ideal gas law, P = nRT/V with R = `IDEAL_GAS_CONSTANT`.
     pub fn get_pressure(&self) -> f64 {
-        C::R_IDEAL_GAS_EQUATION * self.get_total_amount() * self.temperature / self.volume
+        C::IDEAL_GAS_CONSTANT * self.get_total_amount() * self.temperature / self.pressure_volume()
+    }
+
+    /// Volume the pressure is computed over, clamped to `MINIMUM_VOLUME` so that zero or tiny volumes do not divide by zero.
+    fn pressure_volume(&self) -> f64 {
+        self.volume.max(C::MINIMUM_VOLUME)
+    }
+
+    /// Moles of gas that exert `pressure` kPa at `temperature` K in `volume` L, the inverse of `get_pressure`.
+    /// The volume is clamped to `MINIMUM_VOLUME` as `get_pressure` does, so the two stay inverses for tiny volumes.
+    pub fn moles_from_pressure(pressure: f64, temperature: f64, volume: f64) -> f64 {
+        pressure * volume.max(C::MINIMUM_VOLUME) / (C::IDEAL_GAS_CONSTANT * temperature)
+    }
+
+    pub fn partial_pressure(&self, gas: Gas) -> f64 {
+        C::IDEAL_GAS_CONSTANT * self[gas] * self.temperature / self.pressure_volume()
+    }
+
+    /// Partial pressure of every gas, in `ALL_GASES` order, for analyzer readouts.
+    pub fn partial_pressure_array(&self) -> [f64; GAS_COUNT] {
+        let mut pressures = [0.0; GAS_COUNT];
+        for (pressure, gas) in pressures.iter_mut().zip(ALL_GASES.iter()) {
+            *pressure = self.partial_pressure(*gas);
+        }
+
+        pressures
+    }
+
+    pub fn mole_fraction(&self, gas: Gas) -> f64 {
+        let total_amount = self.get_total_amount();
+        if total_amount == 0.0 {
+            0.0
+        } else {
+            self[gas] / total_amount
+        }
+    }
+
+    /// Up to `top_n` present gases with their mole fractions, most abundant first.
+    pub fn dominant_gases(&self, top_n: usize) -> Vec<(Gas, f64)> {
+        let mut fractions: Vec<(Gas, f64)> = self
+            .gases
+            .0
+            .iter()
+            .filter(|(_, amount)| **amount > 0.0)
+            .map(|(gas, _)| (gas, self.mole_fraction(gas)))
+            .collect();
+        fractions.sort_by(|(_, lhs), (_, rhs)| rhs.partial_cmp(lhs).unwrap_or(Ordering::Equal));
+        fractions.truncate(top_n);
+
+        fractions
+    }
+
+    /// Canister label such as "Plasma (72%), Oxygen (21%), trace", listing the `LABEL_GAS_COUNT` most abundant gases.
+    pub fn label(&self) -> String {
+        let dominant = self.dominant_gases(C::LABEL_GAS_COUNT);
+        if dominant.is_empty() {
+            return "empty".to_string();
+        }
+
+        let mut parts: Vec<String> = dominant
+            .iter()
+            .map(|(gas, fraction)| {
+                let name = gas.canonical_name();
+                format!("{}{} ({:.0}%)", name[..1].to_uppercase(), &name[1..], fraction * 100.)
+            })
+            .collect();
+        if self.dominant_gases(usize::MAX).len() > dominant.len() {
+            parts.push("trace".to_string());
+        }
+
+        parts.join(", ")
+    }
+
+    /// What to do with each gas in `targets` so that it ends up at the paired partial pressure, at the current temperature
+    /// and over the same clamped volume as `partial_pressure`, in the order of `targets`.
+    pub fn scrub_plan(&self, targets: &[(Gas, f64)]) -> Vec<ScrubAction> {
+        targets
+            .iter()
+            .map(|(gas, pressure)| {
+                let target_moles = GasMixture::moles_from_pressure(*pressure, self.temperature, self.pressure_volume());
+                let excess = self[*gas] - target_moles;
+                if excess >= 0.0 {
+                    ScrubAction::Remove(*gas, excess)
+                } else {
+                    ScrubAction::Add(*gas, -excess)
+                }
+            })
+            .collect()
+    }
+
+    pub fn n2o_sleep_level(&self) -> SleepLevel {
+        let n2o_pressure = self.partial_pressure(Gas::N2O);
+        if n2o_pressure >= C::N2O_SLEEP_PARTIAL_PRESSURE {
+            SleepLevel::Sleeping
+        } else if n2o_pressure >= C::N2O_DROWSY_PARTIAL_PRESSURE {
+            SleepLevel::Drowsy
+        } else {
+            SleepLevel::None
+        }
+    }
+
+    /// Gases present in at least the amount needed to show as an overlay, in `ALL_GASES` order.
+    pub fn visible_gases(&self) -> Vec<Gas> {
+        ALL_GASES
+            .iter()
+            .copied()
+            .filter(|&gas| matches!(gas.visibility_thresholds(), Some((visible, _)) if self[gas] >= visible))
+            .collect()
+    }
+
+    /// Opacity of the overlay of `gas`, for a renderer's alpha channel: 0 up to the amount at which it shows,
+    /// rising linearly to 1 at the amount at which it is fully opaque. Invisible gases are always 0.
+    pub fn fog_density(&self, gas: Gas) -> f64 {
+        match gas.visibility_thresholds() {
+            Some((visible, opaque)) => ((self[gas] - visible) / (opaque - visible)).clamp(0.0, 1.0),
+            None => 0.0,
+        }
+    }
+
+    pub fn cmp_by_pressure(&self, other: &GasMixture) -> Ordering {
+        self.get_pressure().total_cmp(&other.get_pressure())
+    }
+
+    pub fn cmp_by_temperature(&self, other: &GasMixture) -> Ordering {
+        self.temperature.total_cmp(&other.temperature)
+    }
+
+    /// Single sortable danger rating: relative overpressure, relative deviation from room temperature
+    /// and the partial pressure of toxic gases measured against their safe limit. Standard air scores zero.
+    pub fn hazard_score(&self) -> f64 {
+        let overpressure = (self.get_pressure() / C::ONE_ATMOSPHERE - 1.).max(0.);
+        let heat = (self.temperature - C::T20C).abs() / C::T20C;
+        let toxicity = [Gas::Pl, Gas::CO2, Gas::N2O]
+            .iter()
+            .map(|gas| self.partial_pressure(*gas))
+            .sum::<f64>()
+            / C::HAZARD_TOXIC_PARTIAL_PRESSURE;
+
+        overpressure + heat + toxicity
+    }
+
+    pub fn cmp_by_hazard_score(&self, other: &GasMixture) -> Ordering {
+        self.hazard_score().total_cmp(&other.hazard_score())
+    }
+
+    /// Every alarm condition the mixture triggers under the default `AlarmThresholds`.
+    pub fn hazards(&self) -> Vec<Hazard> {
+        self.hazards_with(&AlarmThresholds::default())
+    }
+
+    pub fn hazards_with(&self, thresholds: &AlarmThresholds) -> Vec<Hazard> {
+        let mut hazards = Vec::new();
+
+        let pressure = self.get_pressure();
+        if pressure < thresholds.min_pressure {
+            hazards.push(Hazard::LowPressure);
+        } else if pressure > thresholds.max_pressure {
+            hazards.push(Hazard::HighPressure);
+        }
+
+        if self.temperature < thresholds.min_temperature {
+            hazards.push(Hazard::LowTemperature);
+        } else if self.temperature > thresholds.max_temperature {
+            hazards.push(Hazard::HighTemperature);
+        }
+
+        hazards.extend(
+            thresholds
+                .max_partial_pressure
+                .iter()
+                .filter(|(gas, max)| self.partial_pressure(*gas) > **max)
+                .map(|(gas, _)| Hazard::ExcessGas(gas)),
+        );
+
+        hazards
+    }
+
+    pub fn pressure_after_temperature(&self, target_k: f64) -> f64 {
+        C::IDEAL_GAS_CONSTANT * self.get_total_amount() * target_k / self.pressure_volume()
+    }
+
+    /// Thermal energy to add before a fire can start, zero if one already can.
+    /// Infinite if there is not enough fuel and oxidizer for any fire, or hyper-noblium suppresses them.
+    pub fn energy_to_ignition(&self) -> f64 {
+        if !R::verify_hnob(self) {
+            return f64::INFINITY;
+        }
+
+        R::FIRE_REACTION_SPECS
+            .iter()
+            .filter(|spec| spec.requirements_met(self))
+            .map(|spec| ((spec.min_temperature - self.temperature) * self.get_heat_cap()).max(0.0))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Thermal energy a heater can add before the mixture either can ignite or goes above `max_pressure`.
+    /// Infinite if it holds no fuel and the pressure limit is unbounded or unreachable.
+    pub fn safe_heat_headroom(&self, max_pressure: f64) -> f64 {
+        let pressure_per_kelvin = self.pressure_after_temperature(1.0);
+        let pressure_limit = if pressure_per_kelvin > 0.0 {
+            ((max_pressure / pressure_per_kelvin - self.temperature) * self.get_heat_cap()).max(0.0)
+        } else {
+            f64::INFINITY
+        };
+
+        self.energy_to_ignition().min(pressure_limit)
     }
 
     pub fn adjust_thermal_energy(&self, energy: f64) -> Self {
@@ -37,25 +450,315 @@ impl GasMixture {
             panic!("Null gas mixes may not have energy");
         }
 
-        Self {
-            temperature: (self.get_energy() + energy) / self.get_heat_cap(),
-            ..*self
+        let mut result = *self;
+        result.set_temperature((self.get_energy() + energy) / self.get_heat_cap());
+        result
+    }
+
+    /// Set the temperature, flooring it at TCMB like every other way of changing a mixture's temperature.
+    pub fn set_temperature(&mut self, temperature: f64) {
+        self.temperature = if temperature < C::TCMB {
+            C::TCMB
+        } else {
+            temperature
+        };
+    }
+
+    /// Same gases and volume at another temperature, floored at TCMB like `set_temperature`.
+    pub fn clone_at_temperature(&self, kelvin: f64) -> GasMixture {
+        let mut result = *self;
+        result.set_temperature(kelvin);
+        result
+    }
+
+    /// Reestablish the temperature invariants of a mixture built with `from_parts`.
+    /// Gases, temperature and volume are the stored source of truth; energy and pressure are always derived from them.
+    pub fn recompute_from_temperature(&mut self) {
+        self.set_temperature(self.temperature);
+    }
+
+    /// Make `energy` the source of truth, deriving the temperature from it and the current gases.
+    /// Use this after replacing the gases with struct update syntax (`GasMixture { gases, ..gm }`), which keeps the
+    /// old temperature and so silently changes the thermal energy. A mixture without heat capacity is left unchanged.
+    pub fn recompute_from_energy(&mut self, energy: f64) {
+        if self.get_heat_cap() > 0.0 {
+            self.set_temperature(energy / self.get_heat_cap());
         }
     }
 
-    pub fn mix_with(&self, other: &GasMixture) -> Self {
-        let lhs_energy = self.get_energy();
-        let lhs_cap = self.get_heat_cap();
-        let rhs_energy = other.get_energy();
-        let rhs_cap = other.get_heat_cap();
+    pub fn adjust_thermal_energy_mut(&mut self, energy: f64) {
+        *self = self.adjust_thermal_energy(energy);
+    }
+
+    /// Conduct heat between two mixtures without sharing gas, as through a wall.
+    /// A `coefficient` of 1 brings them to a common temperature, smaller ones move that fraction of the way; total energy is conserved.
+    pub fn exchange_heat(&mut self, other: &mut GasMixture, coefficient: f64) {
+        let self_cap = self.get_heat_cap();
+        let other_cap = other.get_heat_cap();
+        if self_cap <= 0.0 || other_cap <= 0.0 {
+            return;
+        }
 
+        let heat = coefficient.clamp(0.0, 1.0)
+            * (self.temperature - other.temperature)
+            * self_cap
+            * other_cap
+            / (self_cap + other_cap);
+        self.temperature -= heat / self_cap;
+        other.temperature += heat / other_cap;
+    }
+
+    /// Replace the gases of this tile with the weighted average of itself and its `neighbors`, each neighbor weighing 1.
+    /// The temperature is set so the tile holds the same weighted average of the energies, so a uniform set stays put.
+    /// This is lossy equalization, not conservative sharing: the neighbors are left untouched, so gas and energy
+    /// across the set are only conserved once every tile has been averaged with the same weights.
+    pub fn average_with(&mut self, neighbors: &[&GasMixture], self_weight: f64) {
+        let total_weight = self_weight + neighbors.len() as f64;
+        if total_weight <= 0.0 {
+            return;
+        }
+
+        let (gases, energy) = neighbors.iter().fold(
+            (self.gases * self_weight, self.get_energy() * self_weight),
+            |(gases, energy), neighbor| (gases + neighbor.gases, energy + neighbor.get_energy()),
+        );
+        self.gases = gases * (1. / total_weight);
+        let heat_cap = self.get_heat_cap();
+        if heat_cap > 0.0 {
+            self.set_temperature(energy / total_weight / heat_cap);
+        }
+    }
+
+    pub fn merge_mut(&mut self, other: &GasMixture) {
+        *self = self.mix_with(other);
+    }
+
+    /// Temperature the mixture would have after merging `other` into it, without building the merged mixture.
+    /// Merging two mixtures without heat capacity leaves the temperature of this one.
+    pub fn preview_merge_temperature(&self, other: &GasMixture) -> f64 {
+        mix_temperatures(self.get_heat_cap(), self.temperature, other.get_heat_cap(), other.temperature)
+    }
+
+    pub fn mix_with(&self, other: &GasMixture) -> Self {
         Self {
             gases: self.gases + other.gases,
-            temperature: (lhs_energy + rhs_energy) / (lhs_cap + rhs_cap),
+            temperature: mix_temperatures(self.get_heat_cap(), self.temperature, other.get_heat_cap(), other.temperature),
             volume: self.volume + other.volume,
         }
     }
 
+    /// Deterministic FNV-1a hash of the mixture with gases, temperature and volume rounded to their `FINGERPRINT_*_QUANTUM`.
+    /// Mixtures within half a quantum of each other usually share a fingerprint, but values straddling a rounding boundary will not,
+    /// and as with any 64-bit hash distinct mixtures may rarely collide.
+    pub fn fingerprint(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+        self.gases
+            .0
+            .values()
+            .map(|amount| amount / C::FINGERPRINT_MOLE_QUANTUM)
+            .chain(std::iter::once(self.temperature / C::FINGERPRINT_TEMPERATURE_QUANTUM))
+            .chain(std::iter::once(self.volume / C::FINGERPRINT_VOLUME_QUANTUM))
+            .flat_map(|value| (value.round() as i64).to_le_bytes())
+            .fold(FNV_OFFSET, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME))
+    }
+
+    /// Lowest and highest temperature gates among the reactions whose gas requirements this mixture meets.
+    pub fn active_reaction_temperature_range(&self) -> Option<(f64, f64)> {
+        R::STANDARD_REACTION_SPECS
+            .iter()
+            .filter(|spec| spec.requirements_met(self))
+            .map(|spec| spec.min_temperature)
+            .fold(None, |range, t| match range {
+                None => Some((t, t)),
+                Some((low, high)) => Some((t.min(low), t.max(high))),
+            })
+    }
+
+    /// Pick a gas with probability proportional to its mole fraction, or `None` if the mixture holds no gas.
+    /// `rng` must yield uniformly distributed values in `[0, 1)`; pass a seeded generator for reproducible results.
+    pub fn sample_escaping_gas(&self, rng: &mut impl FnMut() -> f64) -> Option<Gas> {
+        let present = || self.gases.0.iter().filter(|(_, amount)| **amount > 0.0);
+        let total: f64 = present().map(|(_, amount)| amount).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut target = rng() * total;
+        let mut last = None;
+        for (gas, amount) in present() {
+            if target < *amount {
+                return Some(gas);
+            }
+            target -= amount;
+            last = Some(gas);
+        }
+
+        last
+    }
+
+    /// Remove all of `gas` from the mixture, returning it at the current temperature so it carries its share of the thermal energy.
+    /// The returned mixture has no volume of its own, so merging it back restores the original.
+    pub fn split_off_gas(&mut self, gas: Gas) -> GasMixture {
+        let amount = self[gas];
+        self.gases.0[gas] = 0.0;
+
+        GasMixture {
+            gases: GasVec::single(gas, amount),
+            temperature: self.temperature,
+            volume: 0.0,
+        }
+    }
+
+    /// Heat released if all tritium and then all plasma burned at once, limited by the available oxygen.
+    /// Tritium burns first as in `react_once`, and plasma is assumed to burn at full temperature scale where it uses the least oxygen.
+    pub fn explosion_potential(&self) -> f64 {
+        let trit_oxygen_rate = 1. - 1. / C::TRITIUM_BURN_TRIT_FACTOR;
+        let burned_trit = self[Gas::H2].min(self[Gas::O2] / trit_oxygen_rate);
+        let remaining_o2 = self[Gas::O2] - burned_trit * trit_oxygen_rate;
+
+        let plasma_oxygen_rate = C::OXYGEN_BURN_RATE_BASE - 1.;
+        let burned_plasma = self[Gas::Pl].min(remaining_o2 / plasma_oxygen_rate);
+
+        burned_trit * C::FIRE_HYDROGEN_ENERGY_RELEASED * C::TRITIUM_BURN_TRIT_FACTOR
+            + burned_plasma * C::FIRE_PLASMA_ENERGY_RELEASED
+    }
+
+    /// Model a breached tile: vent `conductivity` of the gas to space, returning what was lost, and cool the rest the same fraction of the way towards TCMB.
+    /// Space is an infinite reservoir at TCMB, so the cooling is set directly through `set_temperature` rather than exchanged with `exchange_heat`.
+    pub fn expose_to_space(&mut self, conductivity: f64) -> GasMixture {
+        let conductivity = conductivity.clamp(0.0, 1.0);
+        let lost = GasMixture {
+            gases: self.gases * conductivity,
+            volume: 0.0,
+            ..*self
+        };
+
+        self.gases = self.gases * (1. - conductivity);
+        self.set_temperature(self.temperature - (self.temperature - C::TCMB) * conductivity);
+
+        lost
+    }
+
+    /// Add `moles` of `gas` at the mixture's own temperature, which therefore stays unchanged.
+    pub fn add_gas(&mut self, gas: Gas, moles: f64) {
+        self.gases = self.gases + GasVec::single(gas, moles);
+    }
+
+    pub fn with_gas(mut self, gas: Gas, moles: f64) -> Self {
+        self.add_gas(gas, moles);
+        self
+    }
+
+    /// Zero out every gas with less than `epsilon` moles, usually `MINIMUM_MOLE_COUNT`, handing their heat to the remaining gases.
+    pub fn prune_traces(&mut self, epsilon: f64) {
+        let energy = self.get_energy();
+        self.gases = GasVec(GasEnumMap::from(|g| {
+            if self.gases[g].abs() < epsilon {
+                0.0
+            } else {
+                self.gases[g]
+            }
+        }));
+
+        if self.get_heat_cap() > 0.0 {
+            self.set_temperature(energy / self.get_heat_cap());
+        }
+    }
+
+    /// The given fraction of the mixture's gas and volume, at the same temperature.
+    pub fn portion(&self, fraction: f64) -> GasMixture {
+        GasMixture {
+            gases: self.gases * fraction,
+            temperature: self.temperature,
+            volume: self.volume * fraction,
+        }
+    }
+
+    /// Replay a traced reaction on the mixture, applying both its change in gases and its thermal energy.
+    pub fn apply_delta(self, delta: &R::ReactionOutcome) -> GasMixture {
+        self.shift_by(delta.delta, delta.energy_released)
+    }
+
+    /// Undo a traced reaction, so that `gm.apply_delta(d).revert_delta(d)` gives back `gm`.
+    pub fn revert_delta(self, delta: &R::ReactionOutcome) -> GasMixture {
+        self.shift_by(delta.delta * -1., -delta.energy_released)
+    }
+
+    fn shift_by(self, gases: GasVec, energy: f64) -> GasMixture {
+        let mut result = GasMixture {
+            gases: self.gases + gases,
+            ..self
+        };
+        if result.get_heat_cap() > 0.0 {
+            result.set_temperature((self.get_energy() + energy) / result.get_heat_cap());
+        }
+
+        result
+    }
+
+    /// Split into `n` equal parts at the same temperature, each with `1 / n` of the gas and volume.
+    pub fn split_into(self, n: usize) -> Vec<GasMixture> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self],
+            _ => vec![self.portion(1. / n as f64); n],
+        }
+    }
+
+    /// Power in watts the reactions of the next tick would deliver to a perfect heat sink, negative if they absorb heat.
+    pub fn estimated_power_output(&self) -> f64 {
+        let (_, heat) = R::react_once_isothermal(*self);
+        joules_per_tick_to_watts(heat, C::ATMOS_TICK_SECONDS)
+    }
+
+    /// React once and report what happened, running the reactions a single time.
+    pub fn tick_report(self) -> TickReport {
+        let (result, outcomes) = R::react_once_traced(self);
+
+        TickReport {
+            result,
+            reactions_fired: outcomes.iter().map(|outcome| outcome.name).collect(),
+            energy_released: outcomes.iter().map(|outcome| outcome.energy_released).sum(),
+            radiation: outcomes.iter().map(R::radiation_released).sum(),
+            events: R::events_between(&self, &result),
+        }
+    }
+
+    pub fn react_once_in_place(&mut self) {
+        *self = R::react_once(*self);
+    }
+
+    /// Whether `react_once` is guaranteed to leave the mixture unchanged, judged from the reaction gates alone.
+    pub fn is_stable(&self) -> bool {
+        !R::active_reaction_specs(self).iter().any(|spec| spec.can_react(self))
+    }
+
+    /// Whether every gas amount and the temperature of `other` are within `tolerance` of this mixture's,
+    /// relative to the larger magnitude or absolute below 1. Volumes must match exactly.
+    pub fn approx_equals(&self, other: &GasMixture, tolerance: f64) -> bool {
+        let close = |lhs: f64, rhs: f64| (lhs - rhs).abs() <= tolerance * lhs.abs().max(rhs.abs()).max(1.0);
+
+        self.volume == other.volume
+            && close(self.temperature, other.temperature)
+            && ALL_GASES.iter().all(|&gas| close(self[gas], other[gas]))
+    }
+
+    pub fn checked_add(self, other: GasMixture) -> Result<GasMixture, ArithmeticError> {
+        let result = self.mix_with(&other);
+
+        if let Some((gas, _)) = result.gases.0.iter().find(|(_, a)| !a.is_finite()) {
+            Err(ArithmeticError::NonFiniteAmount(gas))
+        } else if !result.temperature.is_finite() {
+            Err(ArithmeticError::NonFiniteTemperature)
+        } else if !result.volume.is_finite() {
+            Err(ArithmeticError::NonFiniteVolume)
+        } else {
+            Ok(result)
+        }
+    }
+
     pub fn with_energy(gases: GasVec, energy: f64, volume: f64) -> Self {
         if gases.get_heat_cap() == 0.0 {
             panic!("Null gas mixes may not have energy");
@@ -68,15 +771,133 @@ impl GasMixture {
         }
     }
 
+    pub fn new(gases: GasVec, temperature: f64, volume: f64) -> Self {
+        let mut result = Self {
+            gases,
+            temperature,
+            volume,
+        };
+        result.set_temperature(temperature);
+        result
+    }
+
+    /// Build a mixture exactly as given, without flooring the temperature at TCMB like `new` does.
+    /// `recompute_from_temperature` reestablishes the floor afterwards.
+    pub fn from_parts(gases: GasVec, temperature: f64, volume: f64) -> Self {
+        Self {
+            gases,
+            temperature,
+            volume,
+        }
+    }
+
+    /// Build a mixture from gas/mole pairs, summing the amounts of any gas listed more than once.
+    /// Fails on the first negative amount.
+    pub fn from_moles(moles: &[(Gas, f64)], temperature: f64, volume: f64) -> Result<Self, ArithmeticError> {
+        let mut gases = gen_gas_vec!();
+        for &(gas, amount) in moles {
+            if amount < 0.0 {
+                return Err(ArithmeticError::NegativeAmount(gas));
+            }
+            gases[gas] += amount;
+        }
+
+        Ok(Self::new(gases, temperature, volume))
+    }
+
+    pub fn standard_air() -> Self {
+        gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => C::MOLES_CELLSTANDARD * C::O2STANDARD,
+                Gas::N2 => C::MOLES_CELLSTANDARD * C::N2STANDARD,
+            )
+            at(C::T20C)
+            in(C::CELL_VOLUME)
+        )
+    }
+
     pub fn zero() -> Self {
         GasMixture {
             gases: gen_gas_vec!(),
-            temperature: 0.0,
+            temperature: C::TCMB,
             volume: 0.0
         }
     }
 }
 
+/// Blend as much of `a` and `b` as possible into a mixture whose `gas_a`:`gas_b` partial pressure ratio is `target_ratio`.
+/// The whole of one source is used along with the matching portion of the other; returns `None` if no blend reaches the ratio.
+pub fn mix_to_ratio(
+    a: &GasMixture,
+    b: &GasMixture,
+    gas_a: Gas,
+    gas_b: Gas,
+    target_ratio: f64,
+) -> Option<GasMixture> {
+    // Solve s * (r * a[B] - a[A]) == t * (b[A] - r * b[B]) for the portions s of `a` and t of `b`
+    let p = target_ratio * a[gas_b] - a[gas_a];
+    let q = b[gas_a] - target_ratio * b[gas_b];
+
+    let (s, t) = if p == 0.0 && q == 0.0 {
+        (1.0, 1.0)
+    } else if p == 0.0 {
+        (1.0, 0.0)
+    } else if q == 0.0 {
+        (0.0, 1.0)
+    } else if p.signum() != q.signum() {
+        return None;
+    } else if q.abs() >= p.abs() {
+        (1.0, p / q)
+    } else {
+        (q / p, 1.0)
+    };
+
+    let result = a.portion(s) + b.portion(t);
+    if result[gas_b] == 0.0 {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+/// `steps` copies of `base` at evenly spaced temperatures from `from` to `to`, both included.
+pub fn temperature_sweep(base: &GasMixture, from: f64, to: f64, steps: usize) -> Vec<GasMixture> {
+    match steps {
+        0 => Vec::new(),
+        1 => vec![base.clone_at_temperature(from)],
+        _ => (0..steps)
+            .map(|step| {
+                let fraction = step as f64 / (steps - 1) as f64;
+                base.clone_at_temperature(from + (to - from) * fraction)
+            })
+            .collect(),
+    }
+}
+
+/// Temperature two bodies with heat capacities `hc_a` and `hc_b` settle at when brought together, conserving their energy.
+/// A side without heat capacity holds no energy and takes the other's temperature; if neither has any, `t_a` is kept.
+pub fn mix_temperatures(hc_a: f64, t_a: f64, hc_b: f64, t_b: f64) -> f64 {
+    let heat_cap = hc_a + hc_b;
+    if heat_cap <= 0.0 {
+        return t_a;
+    }
+
+    (hc_a * t_a + hc_b * t_b) / heat_cap
+}
+
+pub fn joules_per_tick_to_watts(joules: f64, tick_seconds: f64) -> f64 {
+    joules / tick_seconds
+}
+
+/// Cryogenic distillation of `target` out of `gm`, returning `(pure target, remainder)`.
+/// This is an air-processing utility rather than a reaction: nothing is converted, the species are only separated.
+pub fn distill(gm: GasMixture, target: Gas) -> (GasMixture, GasMixture) {
+    let mut remainder = gm;
+    let distillate = remainder.split_off_gas(target);
+
+    (distillate, remainder)
+}
+
 impl Add<GasMixture> for GasMixture {
     type Output = Self;
 
@@ -93,6 +914,18 @@ impl Add<f64> for GasMixture {
     }
 }
 
+impl AddAssign<GasMixture> for GasMixture {
+    fn add_assign(&mut self, rhs: GasMixture) {
+        self.merge_mut(&rhs);
+    }
+}
+
+impl AddAssign<f64> for GasMixture {
+    fn add_assign(&mut self, rhs: f64) {
+        self.adjust_thermal_energy_mut(rhs);
+    }
+}
+
 impl Index<Gas> for GasMixture {
     type Output = f64;
 
@@ -0,0 +1,168 @@
+extern crate enum_map;
+
+use crate::gas::*;
+use crate::GasMixture;
+use enum_map::EnumMap;
+
+/// Mixtures `GasMixtureSoA::react_all` gathers at once, small enough for the scratch buffer to stay in L1 cache.
+const REACT_CHUNK: usize = 64;
+
+/// Columnar storage for large batches of gas mixtures, one `Vec` per gas.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GasMixtureSoA {
+    pub gases: EnumMap<Gas, Vec<f64>>,
+    pub temperature: Vec<f64>,
+    pub volume: Vec<f64>,
+}
+
+impl GasMixtureSoA {
+    pub fn len(&self) -> usize {
+        self.temperature.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.temperature.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> GasMixture {
+        GasMixture {
+            gases: GasVec(GasEnumMap::from(|gas| self.gases[gas][index])),
+            temperature: self.temperature[index],
+            volume: self.volume[index],
+        }
+    }
+
+    pub fn set(&mut self, index: usize, gm: GasMixture) {
+        for (gas, amount) in gm.gases.0.iter() {
+            self.gases[gas][index] = *amount;
+        }
+        self.temperature[index] = gm.temperature();
+        self.volume[index] = gm.volume;
+    }
+
+    pub fn push(&mut self, gm: GasMixture) {
+        for (gas, amount) in gm.gases.0.iter() {
+            self.gases[gas].push(*amount);
+        }
+        self.temperature.push(gm.temperature());
+        self.volume.push(gm.volume);
+    }
+
+    /// Heat capacity of every mixture in the batch.
+    pub fn heat_capacities(&self) -> Vec<f64> {
+        #[cfg(feature = "simd")]
+        return self.weighted_sums_simd(Gas::specific_heat);
+        #[cfg(not(feature = "simd"))]
+        return self.weighted_sums_scalar(Gas::specific_heat);
+    }
+
+    /// Total moles of every mixture in the batch.
+    pub fn total_moles(&self) -> Vec<f64> {
+        #[cfg(feature = "simd")]
+        return self.weighted_sums_simd(|_| 1.0);
+        #[cfg(not(feature = "simd"))]
+        return self.weighted_sums_scalar(|_| 1.0);
+    }
+
+    /// Per mixture sum of each gas amount times `weight(gas)`, adding the gases in enum order like `GasVec`.
+    #[cfg(any(test, not(feature = "simd")))]
+    pub(crate) fn weighted_sums_scalar(&self, weight: impl Fn(Gas) -> f64) -> Vec<f64> {
+        (0..self.len())
+            .map(|index| {
+                self.gases
+                    .iter()
+                    .map(|(gas, amounts)| amounts[index] * weight(gas))
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Same as `weighted_sums_scalar`, and bit for bit equal to it, but adding up `std::simd` vectors of `LANES`
+    /// mixtures at a time, one column after another over cache-sized blocks of mixtures.
+    #[cfg(feature = "simd")]
+    pub(crate) fn weighted_sums_simd(&self, weight: impl Fn(Gas) -> f64) -> Vec<f64> {
+        use std::simd::Simd;
+
+        const LANES: usize = 8;
+        const BLOCK: usize = 64 * LANES;
+
+        let weights: Vec<(Gas, f64)> = self.gases.iter().map(|(gas, _)| (gas, weight(gas))).collect();
+        let mut sums = vec![0.0; self.len()];
+        for (block_index, block) in sums.chunks_mut(BLOCK).enumerate() {
+            let offset = block_index * BLOCK;
+            for (gas, w) in weights.iter() {
+                let amounts = &self.gases[*gas][offset..offset + block.len()];
+                let (sum_chunks, sum_remainder) = block.as_chunks_mut::<LANES>();
+                let (amount_chunks, amount_remainder) = amounts.as_chunks::<LANES>();
+
+                let w_lanes = Simd::splat(*w);
+                for (sum, amount) in sum_chunks.iter_mut().zip(amount_chunks) {
+                    *sum = (Simd::from_array(*sum) + Simd::from_array(*amount) * w_lanes).to_array();
+                }
+                for (sum, amount) in sum_remainder.iter_mut().zip(amount_remainder) {
+                    *sum += amount * w;
+                }
+            }
+        }
+
+        sums
+    }
+
+    /// React every mixture once. The reactions work on whole mixtures, so they are gathered `REACT_CHUNK` at a time
+    /// into a scratch buffer, reading each column sequentially, and scattered back the same way.
+    pub fn react_all(&mut self) {
+        let mut chunk = Vec::with_capacity(REACT_CHUNK.min(self.len()));
+        for start in (0..self.len()).step_by(REACT_CHUNK) {
+            let end = (start + REACT_CHUNK).min(self.len());
+
+            chunk.clear();
+            chunk.resize(end - start, GasMixture::zero());
+            for (gas, amounts) in self.gases.iter() {
+                for (gm, amount) in chunk.iter_mut().zip(&amounts[start..end]) {
+                    gm.gases[gas] = *amount;
+                }
+            }
+            for (gm, (temperature, volume)) in chunk
+                .iter_mut()
+                .zip(self.temperature[start..end].iter().zip(&self.volume[start..end]))
+            {
+                gm.temperature = *temperature;
+                gm.volume = *volume;
+            }
+
+            chunk.iter_mut().for_each(GasMixture::react_once_in_place);
+
+            for (gas, amounts) in self.gases.iter_mut() {
+                for (amount, gm) in amounts[start..end].iter_mut().zip(&chunk) {
+                    *amount = gm[gas];
+                }
+            }
+            for (gm, (temperature, volume)) in chunk
+                .iter()
+                .zip(self.temperature[start..end].iter_mut().zip(&mut self.volume[start..end]))
+            {
+                *temperature = gm.temperature();
+                *volume = gm.volume;
+            }
+        }
+    }
+}
+
+impl From<Vec<GasMixture>> for GasMixtureSoA {
+    fn from(gms: Vec<GasMixture>) -> Self {
+        let mut result = GasMixtureSoA {
+            gases: EnumMap::from(|_| Vec::with_capacity(gms.len())),
+            temperature: Vec::with_capacity(gms.len()),
+            volume: Vec::with_capacity(gms.len()),
+        };
+        gms.into_iter().for_each(|gm| result.push(gm));
+
+        result
+    }
+}
+
+impl From<GasMixtureSoA> for Vec<GasMixture> {
+    fn from(soa: GasMixtureSoA) -> Self {
+        (0..soa.len()).map(|index| soa.get(index)).collect()
+    }
+}
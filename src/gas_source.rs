@@ -0,0 +1,177 @@
+use crate::GasMixture;
+
+/// Iterations of the bisection in `transfer_until_pressure`, enough to exhaust f64 precision.
+const TRANSFER_SEARCH_STEPS: usize = 64;
+
+/// Anything gas can be pumped out of and into: bare mixtures, canisters, pipe networks.
+///
+/// Other crates can implement it for their own containers and reuse the transfer functions:
+///
+/// ```
+/// use tg_atmos_sim::gas_source::pump_moles;
+/// use tg_atmos_sim::{GasMixture, GasSource};
+///
+/// struct Canister {
+///     air: GasMixture,
+///     valve_open: bool,
+/// }
+///
+/// impl GasSource for Canister {
+///     fn remove(&mut self, moles: f64) -> GasMixture {
+///         if self.valve_open { self.air.remove(moles) } else { GasMixture::zero() }
+///     }
+///
+///     fn merge(&mut self, gm: GasMixture) {
+///         self.air.merge(gm);
+///     }
+///
+///     fn get_pressure(&self) -> f64 {
+///         self.air.get_pressure()
+///     }
+///
+///     fn total_moles(&self) -> f64 {
+///         self.air.get_total_amount()
+///     }
+/// }
+///
+/// let mut canister = Canister { air: GasMixture::standard_air(), valve_open: true };
+/// let mut room = GasMixture::standard_air();
+/// assert_eq!(pump_moles(&mut canister, &mut room, 10.0), 10.0);
+/// ```
+pub trait GasSource {
+    /// Take out up to `moles` of gas, keeping the composition, and return it as a volumeless mixture.
+    fn remove(&mut self, moles: f64) -> GasMixture;
+
+    /// Take in `gm`, conserving its energy; the volume of the source is unaffected.
+    fn merge(&mut self, gm: GasMixture);
+
+    fn get_pressure(&self) -> f64;
+
+    fn total_moles(&self) -> f64;
+}
+
+impl GasSource for GasMixture {
+    fn remove(&mut self, moles: f64) -> GasMixture {
+        let total_moles = self.get_total_amount();
+        if total_moles <= 0.0 || moles <= 0.0 {
+            return GasMixture::zero();
+        }
+
+        let fraction = (moles / total_moles).min(1.0);
+        let removed = GasMixture {
+            gases: self.gases * fraction,
+            temperature: self.temperature(),
+            volume: 0.0,
+        };
+        self.gases = self.gases * (1. - fraction);
+
+        removed
+    }
+
+    fn merge(&mut self, gm: GasMixture) {
+        // Gases without heat capacity, such as BZ, still have to be kept; `mix_temperatures` handles a zero sum
+        let volume = self.volume;
+        self.merge_mut(&gm);
+        self.volume = volume;
+    }
+
+    fn get_pressure(&self) -> f64 {
+        GasMixture::get_pressure(self)
+    }
+
+    fn total_moles(&self) -> f64 {
+        self.get_total_amount()
+    }
+}
+
+/// Move up to `moles` of gas from `source` to `target`, returning how much was actually moved.
+pub fn pump_moles<S: GasSource, T: GasSource>(source: &mut S, target: &mut T, moles: f64) -> f64 {
+    let moved = source.remove(moles.min(source.total_moles()));
+    let moved_moles = moved.get_total_amount();
+    target.merge(moved);
+
+    moved_moles
+}
+
+/// Move gas from `source` to `target` until the target reaches `target_pressure` or the source runs dry.
+/// The amount is found by bisection on copies of both, so it holds regardless of how either stores its gas.
+/// Returns the moles moved, which is zero if the target is already at or above the pressure.
+pub fn transfer_until_pressure<S, T>(source: &mut S, target: &mut T, target_pressure: f64) -> f64
+where
+    S: GasSource + Clone,
+    T: GasSource + Clone,
+{
+    if target.get_pressure() >= target_pressure {
+        return 0.0;
+    }
+
+    let pressure_after = |moles: f64| {
+        let mut trial_source = source.clone();
+        let mut trial_target = target.clone();
+        pump_moles(&mut trial_source, &mut trial_target, moles);
+        trial_target.get_pressure()
+    };
+
+    let available = source.total_moles();
+    let moles = if pressure_after(available) <= target_pressure {
+        available
+    } else {
+        let (mut low, mut high) = (0.0, available);
+        for _ in 0..TRANSFER_SEARCH_STEPS {
+            let mid = (low + high) / 2.;
+            if pressure_after(mid) < target_pressure {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        high
+    };
+
+    pump_moles(source, target, moles)
+}
+
+/// Open `canister` into `room`: gas flows from the canister until both are at the same pressure or the canister
+/// is empty, mixing into the room at the merged temperature. Moles and energy are conserved.
+/// Nothing flows if the room is already at or above the canister's pressure. Returns the moles released.
+pub fn release_canister(canister: &mut GasMixture, room: &mut GasMixture) -> f64 {
+    if room.get_pressure() >= canister.get_pressure() {
+        return 0.0;
+    }
+
+    let available = canister.get_total_amount();
+    let pressure_difference_after = |moles: f64| {
+        let fraction = moles / available;
+        let released = GasMixture {
+            gases: canister.gases * fraction,
+            ..*canister
+        };
+        let canister_after = GasMixture {
+            gases: canister.gases * (1. - fraction),
+            ..*canister
+        };
+        let room_after = GasMixture {
+            gases: room.gases + released.gases,
+            temperature: room.preview_merge_temperature(&released),
+            volume: room.volume,
+        };
+        canister_after.get_pressure() - room_after.get_pressure()
+    };
+
+    let moles = if pressure_difference_after(available) >= 0.0 {
+        available
+    } else {
+        let (mut low, mut high) = (0.0, available);
+        for _ in 0..TRANSFER_SEARCH_STEPS {
+            let mid = (low + high) / 2.;
+            if pressure_difference_after(mid) > 0.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    };
+
+    pump_moles(canister, room, moles)
+}
@@ -0,0 +1,72 @@
+use crate::GasMixture;
+use std::ops::{Index, IndexMut};
+
+/// Rectangular map of tiles, stored row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    tiles: Vec<GasMixture>,
+}
+
+impl Grid {
+    /// A `width` by `height` grid with every tile holding `fill`.
+    pub fn new(width: usize, height: usize, fill: GasMixture) -> Self {
+        Grid {
+            width,
+            height,
+            tiles: vec![fill; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn tiles(&self) -> &[GasMixture] {
+        &self.tiles
+    }
+
+    /// Conduct heat between every pair of orthogonal neighbours with `GasMixture::exchange_heat`, modelling heat
+    /// superconducting through walls. Pairs are visited row by row, so heat can travel more than one tile
+    /// in a tick along the direction of the sweep. The total energy of the grid is conserved.
+    pub fn superconduct(&mut self, coefficient: f64) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if x + 1 < self.width {
+                    self.exchange_between((x, y), (x + 1, y), coefficient);
+                }
+                if y + 1 < self.height {
+                    self.exchange_between((x, y), (x, y + 1), coefficient);
+                }
+            }
+        }
+    }
+
+    fn exchange_between(&mut self, lhs: (usize, usize), rhs: (usize, usize), coefficient: f64) {
+        let (mut a, mut b) = (self[lhs], self[rhs]);
+        a.exchange_heat(&mut b, coefficient);
+        self[lhs] = a;
+        self[rhs] = b;
+    }
+}
+
+impl Index<(usize, usize)> for Grid {
+    type Output = GasMixture;
+
+    fn index(&self, (x, y): (usize, usize)) -> &GasMixture {
+        assert!(x < self.width && y < self.height, "tile ({}, {}) is outside the grid", x, y);
+        &self.tiles[y * self.width + x]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Grid {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut GasMixture {
+        assert!(x < self.width && y < self.height, "tile ({}, {}) is outside the grid", x, y);
+        &mut self.tiles[y * self.width + x]
+    }
+}
@@ -0,0 +1,91 @@
+use crate::reactions::{self as R, ReactionOutcome};
+use crate::{Gas, GasMixture};
+use std::collections::VecDeque;
+
+/// A mixture edited step by step, with undo and redo, for interactive sandboxes.
+///
+/// Every operation is recorded as a `ReactionOutcome` delta and applied through `GasMixture::apply_delta`; undoing
+/// reverts the last one through `GasMixture::revert_delta` and redoing applies it again, each in constant time.
+/// Stepping back and forth therefore gives back the earlier mixtures to within rounding rather than bit for bit.
+/// Once more than `max_depth` deltas are kept the oldest is dropped and can no longer be undone.
+#[derive(Clone, Debug)]
+pub struct History {
+    current: GasMixture,
+    applied: VecDeque<ReactionOutcome>,
+    undone: Vec<ReactionOutcome>,
+    max_depth: usize,
+}
+
+impl History {
+    pub fn new(gm: GasMixture, max_depth: usize) -> Self {
+        History {
+            current: gm,
+            applied: VecDeque::new(),
+            undone: Vec::new(),
+            max_depth,
+        }
+    }
+
+    pub fn current(&self) -> GasMixture {
+        self.current
+    }
+
+    /// React once.
+    pub fn react(&mut self) {
+        self.record("react", R::react_once(self.current));
+    }
+
+    /// Add `moles` of `gas` at the mixture's temperature, negative to remove it.
+    pub fn add(&mut self, gas: Gas, moles: f64) {
+        self.record("add", self.current.with_gas(gas, moles));
+    }
+
+    /// Add `joules` of thermal energy, negative to remove it. A mixture without heat capacity is left unheated.
+    pub fn heat(&mut self, joules: f64) {
+        if self.current.get_heat_cap() > 0.0 {
+            self.record("heat", self.current.adjust_thermal_energy(joules));
+        }
+    }
+
+    /// Step back over the last operation, returning whether there was one to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.applied.pop_back() {
+            Some(delta) => {
+                self.current = self.current.revert_delta(&delta);
+                self.undone.push(delta);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Step forward over the last undone operation, returning whether there was one to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.undone.pop() {
+            Some(delta) => {
+                self.push(delta);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record the operation that took the mixture to `next`, discarding anything that could have been redone.
+    fn record(&mut self, name: &'static str, next: GasMixture) {
+        let delta = ReactionOutcome {
+            name,
+            delta: next.gases - self.current.gases,
+            energy_released: next.get_energy() - self.current.get_energy(),
+        };
+        self.undone.clear();
+        self.push(delta);
+    }
+
+    fn push(&mut self, delta: ReactionOutcome) {
+        self.current = self.current.apply_delta(&delta);
+        self.applied.push_back(delta);
+        if self.applied.len() > self.max_depth {
+            self.applied.pop_front();
+        }
+    }
+}
@@ -1,14 +1,31 @@
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+pub mod analysis;
+pub mod command;
 pub mod constants;
 pub mod gas;
 pub mod gas_mixture;
+pub mod gas_mixture_soa;
+pub mod gas_source;
+pub mod grid;
+pub mod history;
 pub mod reactions;
+#[cfg(any(test, feature = "serde"))]
+pub mod serialization;
 pub mod tests;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub mod macros;
 
 pub use crate::gas::Gas;
 pub use crate::gas::GasVec;
 pub use crate::gas_mixture::GasMixture;
+pub use crate::gas_mixture_soa::GasMixtureSoA;
+pub use crate::gas_source::GasSource;
+pub use crate::grid::Grid;
 pub use enum_map::enum_map;
 
 pub mod ffi;
@@ -51,11 +51,11 @@ macro_rules! gen_gas_mix_with_temp {
         at($temp:expr)
         in($volume:expr)
     } => {
-        GasMixture {
-            gases: $crate::gen_gas_vec!($($t)*),
-            temperature: $temp,
-            volume: $volume
-        }
+        $crate::GasMixture::from_parts(
+            $crate::gen_gas_vec!($($t)*),
+            $temp,
+            $volume
+        )
     };
 }
 
@@ -73,24 +73,107 @@ macro_rules! temperature {
 macro_rules! reaction {
     {
         called($name:ident)
-        with($($g:expr => $ma:expr),+)
+        $(with($($g:expr => $ma:expr),+))?
+        $(with_present($($presg:expr),+))?
+        $(with_partial_pressure($($pg:expr => $pp:expr),+))?
+        $(catalyzed_by($($cg:expr => $effect_name:ident = $effect:expr),+))?
         at($min_temp:expr)
+        $(at_pressure($min_pressure:expr))?
         with_gm_as($gm_name:ident) =>
         $code: tt
     } => {
-        #[inline]
-        pub fn $name($gm_name: $crate::GasMixture) -> $crate::GasMixture {
-            if (
-                $gm_name.temperature >= $min_temp &&
-                $(
-                    $gm_name[$g] >= $ma
-                )&&+
-            ) {
-                $code
-            } else {
-                $gm_name
+        pub mod $name {
+            use super::*;
+
+            pub const REQUIREMENTS: &[($crate::Gas, f64)] = &[
+                $($(($g, $ma),)+)?
+                $($(($presg, $crate::constants::MINIMUM_MOLE_COUNT),)+)?
+            ];
+            pub const PRESENT: &[$crate::Gas] = &[$($($presg),+)?];
+            pub const PARTIAL_PRESSURE_REQUIREMENTS: &[($crate::Gas, f64)] = &[$($(($pg, $pp)),+)?];
+            pub const CATALYSTS: &[$crate::Gas] = &[$($($cg),+)?];
+            pub const MIN_TEMPERATURE: f64 = $min_temp;
+            pub const MIN_PRESSURE: f64 = {
+                let _min_pressure = f64::NEG_INFINITY;
+                $(let _min_pressure = $min_pressure;)?
+                _min_pressure
+            };
+            pub const SPEC: $crate::reactions::ReactionSpec = $crate::reactions::ReactionSpec {
+                name: stringify!($name),
+                requirements: REQUIREMENTS,
+                present: PRESENT,
+                partial_pressure_requirements: PARTIAL_PRESSURE_REQUIREMENTS,
+                catalysts: CATALYSTS,
+                min_temperature: MIN_TEMPERATURE,
+                min_pressure: MIN_PRESSURE,
+            };
+
+            /// Output of the reaction gated like `gated_at`, before rounding negatives are cleared and the temperature is
+            /// floored at TCMB, or `None` if the gate is closed.
+            #[inline]
+            pub fn raw_gated_at($gm_name: $crate::GasMixture, min_temperature: f64, min_moles: f64) -> Option<$crate::GasMixture> {
+                // Reactions without `with_present` gases have no use for it
+                let _ = min_moles;
+                if (
+                    $gm_name.temperature() >= min_temperature
+                    $($(
+                        && $gm_name[$g] >= $ma
+                    )+)?
+                    $($(
+                        && $gm_name[$presg] >= min_moles
+                    )+)?
+                    $($(
+                        && $gm_name.partial_pressure($pg) >= $pp
+                    )+)?
+                    $(
+                        && $gm_name.get_pressure() >= $min_pressure
+                    )?
+                ) {
+                    let reacted: $crate::GasMixture = {
+                        $($(
+                            let $effect_name = $effect;
+                        )+)?
+                        $code
+                    };
+                    $($(
+                        debug_assert_eq!(
+                            reacted[$cg], $gm_name[$cg],
+                            "{} changed the amount of its catalyst {:?}", stringify!($name), $cg
+                        );
+                    )+)?
+                    Some(reacted)
+                } else {
+                    None
+                }
+            }
+
+            /// Run the reaction with its temperature gate at `min_temperature` instead of `MIN_TEMPERATURE`,
+            /// and the gases in `PRESENT` judged present at `min_moles` instead of `MINIMUM_MOLE_COUNT`.
+            #[inline]
+            pub fn gated_at(gm: $crate::GasMixture, min_temperature: f64, min_moles: f64) -> $crate::GasMixture {
+                match raw_gated_at(gm, min_temperature, min_moles) {
+                    Some(mut reacted) => {
+                        reacted.gases = reacted.gases.without_rounding_negatives(&gm.gases);
+                        reacted.set_temperature(reacted.temperature());
+                        reacted
+                    }
+                    None => gm,
+                }
+            }
+
+            /// Output of the reaction at its usual gate before rounding negatives are cleared, `gm` itself if it does not run.
+            // Only standard reactions are audited through it
+            #[allow(dead_code)]
+            #[inline]
+            pub fn raw(gm: $crate::GasMixture) -> $crate::GasMixture {
+                raw_gated_at(gm, MIN_TEMPERATURE, $crate::constants::MINIMUM_MOLE_COUNT).unwrap_or(gm)
             }
         }
+
+        #[inline]
+        pub fn $name(gm: $crate::GasMixture) -> $crate::GasMixture {
+            $name::gated_at(gm, $name::MIN_TEMPERATURE, $crate::constants::MINIMUM_MOLE_COUNT)
+        }
     };
 }
 
@@ -104,12 +187,36 @@ macro_rules! chained_call {
     (
         $starting_value:expr => $target_func:ident => $($rest:ident) => +
     ) => {
-        chained_call! {
+        $crate::chained_call! {
             $target_func($starting_value) => $($rest) => +
         }
     }
 }
 
+/// Declare the standard reaction chain once, in the order `react_once` runs it, generating `react_once_ignore_noblium`
+/// and every per-reaction list that has to follow that order.
+#[macro_export]
+macro_rules! standard_reactions {
+    ($($name:ident),+ $(,)?) => {
+        /// React once like `react_once` but regardless of the amount of hyper-noblium in the mixture.
+        pub fn react_once_ignore_noblium(gm: $crate::GasMixture) -> $crate::GasMixture {
+            $crate::chained_call!(gm => $($name) => +)
+        }
+
+        /// Every reaction in the order `react_once` runs them.
+        pub const STANDARD_REACTIONS: &[(&str, $crate::reactions::Reaction)] = &[$((stringify!($name), $name)),+];
+
+        /// Specs of every reaction in `STANDARD_REACTIONS`, in the same order.
+        pub const STANDARD_REACTION_SPECS: &[$crate::reactions::ReactionSpec] = &[$($name::SPEC),+];
+
+        /// `gated_at` of every reaction in `STANDARD_REACTIONS`, in the same order.
+        const STANDARD_GATED_REACTIONS: &[$crate::reactions::GatedReaction] = &[$($name::gated_at),+];
+
+        /// `raw` of every reaction in `STANDARD_REACTIONS`, in the same order.
+        const STANDARD_RAW_REACTIONS: &[$crate::reactions::Reaction] = &[$($name::raw),+];
+    };
+}
+
 #[macro_export]
 macro_rules! test_reaction{
     (
@@ -162,13 +269,13 @@ macro_rules! test_reaction{
             assert!(
                 approx_eq!(
                     f64,
-                    result.temperature,
-                    g1.temperature,
+                    result.temperature(),
+                    g1.temperature(),
                     epsilon=0.000000001
                 ),
                 "Wrong temperature: {} != {}",
-                result.temperature,
-                g1.temperature
+                result.temperature(),
+                g1.temperature()
             );
 
             $(
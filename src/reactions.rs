@@ -1,27 +1,42 @@
 use crate::constants as C;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use crate::enum_map;
 use crate::gas::*;
+use crate::GasSource;
 use crate::{
-    chained_call, gas_mixture::*, gen_gas_mix_with_energy, gen_gas_vec, reaction, temperature,
+    gas_mixture::*, gen_gas_mix_with_energy, gen_gas_vec, reaction, standard_reactions, temperature,
 };
 
-fn verify_hnob(gm: &GasMixture) -> bool {
-    gm[Gas::HNb] < 5.0
+pub(crate) fn verify_hnob(gm: &GasMixture) -> bool {
+    gm[Gas::HNb] < C::NOBLIUM_SUPPRESSION_THRESHOLD
 }
 
+/// Floored modulo, matching BYOND's behavior for negative operands.
+/// A zero `rhs` returns `lhs` unchanged, and an `rhs` too small for the quotient to be representable yields `0.0`.
 pub fn atmos_mod(lhs: f64, rhs: f64) -> f64 {
-    lhs - rhs * (lhs / rhs).floor()
+    if rhs == 0.0 {
+        return lhs;
+    }
+
+    let quotient = (lhs / rhs).floor();
+    if quotient.is_infinite() {
+        0.0
+    } else {
+        lhs - rhs * quotient
+    }
 }
 
 reaction! (
     called(n2o_decomp)
-    with(
-        Gas::N2O => C::MINIMUM_MOLE_COUNT
+    with_present(
+        Gas::N2O
     )
     at(temperature!(C::N2O_DECOMPOSITION_MIN_ENERGY, K))
     with_gm_as(gm) => {
         let n2o = gm[Gas::N2O];
-        let t = gm.temperature;
+        let t = gm.temperature();
         let burned_fuel = (2e-5 * (t - (1e-5 * t.powi(2)))).max(0.) * n2o;
 
         if burned_fuel <= 0.0 {
@@ -41,17 +56,17 @@ reaction! (
 
 reaction! (
     called(plasma_fire)
-    with(
-        Gas::Pl => C::MINIMUM_MOLE_COUNT,
-        Gas::O2 => C::MINIMUM_MOLE_COUNT
+    with_present(
+        Gas::Pl,
+        Gas::O2
     )
     at(temperature!(C::PLASMA_MINIMUM_BURN_TEMPERATURE, K))
     with_gm_as(gm) => {
         let pl = gm[Gas::Pl];
         let o2 = gm[Gas::O2];
-        let t = gm.temperature;
+        let t = gm.temperature();
 
-        let temp_scale = ((t - C::PLASMA_MINIMUM_BURN_TEMPERATURE) / C::PLASMA_TEMP_SCALE).min(1.);
+        let temp_scale = ((t - C::PLASMA_MINIMUM_BURN_TEMPERATURE) / C::PLASMA_TEMP_SCALE).clamp(0., 1.);
 
         let plasma_burn_rate = pl * temp_scale / C::PLASMA_BURN_RATE_DELTA;
         let plasma_burn_rate = if o2 > pl * C::PLASMA_OXYGEN_FULLBURN {
@@ -70,25 +85,31 @@ reaction! (
         let is_satured = o2 / pl > C::SUPER_SATURATION_THRESHOLD;
         let energy_release = plasma_burn_rate * C::FIRE_PLASMA_ENERGY_RELEASED;
 
-        gm + gen_gas_mix_with_energy!(
-            with (
-                Gas::Pl => -plasma_burn_rate,
-                Gas::O2 => -plasma_burn_rate * oxygen_burn_rate,
-                Gas::H2 if is_satured => plasma_burn_rate,
-                Gas::CO2 if !is_satured => plasma_burn_rate,
+        if plasma_burn_rate <= 0.0 {
+            gm
+        } else {
+            gm + gen_gas_mix_with_energy!(
+                with (
+                    Gas::Pl => -plasma_burn_rate,
+                    Gas::O2 => (-plasma_burn_rate * oxygen_burn_rate).max(-o2),
+                    Gas::H2 if is_satured => plasma_burn_rate,
+                    Gas::CO2 if !is_satured => plasma_burn_rate,
+                )
+                at (energy_release)
             )
-            at (energy_release)
-        )
+        }
     }
 );
 
+// Not conservative: burning tritium yields one mole of water per mole of tritium it would burn on its own,
+// while only using up a TRITIUM_BURN_TRIT_FACTOR-th of it when oxygen is plentiful.
 reaction! (
     called(trit_fire)
-    with(
-        Gas::H2 => C::MINIMUM_MOLE_COUNT,
-        Gas::O2 => C::MINIMUM_MOLE_COUNT
+    with_present(
+        Gas::H2,
+        Gas::O2
     )
-    at(temperature!(100.0, C))
+    at(temperature!(C::TRITIUM_MINIMUM_BURN_TEMPERATURE, K))
     with_gm_as(gm) => {
         let e = gm.get_energy();
         let h2 = gm[Gas::H2];
@@ -112,6 +133,38 @@ reaction! (
     }
 );
 
+/// Thermal energy a fusion mixture holding `e` ends up with after releasing `reaction_energy`, remapped on a logarithmic
+/// scale around a middle energy set by `scale_factor`, which keeps any one tick within a factor of the current energy.
+/// The translation takes the logarithm of `e`, so it is only defined for finite positive `e` and `reaction_energy`;
+/// outside that domain, or if the result overflows, `e` is returned unchanged and no energy is released.
+pub(crate) fn translate_fusion_energy(e: f64, reaction_energy: f64, scale_factor: f64) -> f64 {
+    if reaction_energy == 0.0 || !reaction_energy.is_finite() || !e.is_finite() || e <= 0.0 {
+        return e;
+    }
+
+    let middle_energy = {
+        let alpha = C::FUSION_MOLE_THRESHOLD + C::TOROID_CALCULATED_THRESHOLD * scale_factor / 2.;
+        let beta = 200. * C::FUSION_MIDDLE_ENERGY_REFERENCE;
+
+        alpha * beta
+    };
+    let e_alpha = middle_energy * C::FUSION_ENERGY_TRANSLATION_EXPONENT.powf((e / middle_energy).log10());
+    let bowdlerized = reaction_energy
+        .min(e_alpha * (C::FUSION_ENERGY_TRANSLATION_EXPONENT.powi(2) - 1.))
+        .max(e_alpha * (C::FUSION_ENERGY_TRANSLATION_EXPONENT.powi(-2) - 1.));
+    let new_e = middle_energy * 10_f64.powf(((e_alpha + bowdlerized) / middle_energy).log(C::FUSION_ENERGY_TRANSLATION_EXPONENT));
+
+    if new_e.is_finite() {
+        new_e
+    } else {
+        e
+    }
+}
+
+// Not conservative: the plasma and carbon dioxide amounts are remapped rather than converted, and the waste gases
+// are created from a fixed amount of tritium.
+// Platform-sensitive: log10, powf, log and sin come from the platform's libm, which is not required to round
+// correctly, so fusion results can differ in their last bits between x86 and ARM builds.
 reaction! (
     called(fusion)
     with(
@@ -125,9 +178,11 @@ reaction! (
         let pl = gm.gases[Gas::Pl];
         let co2 = gm.gases[Gas::CO2];
 
+        // Floored at FUSION_MINIMAL_SCALE, so tiny and zero volumes are safe to divide by
         let scale_factor = (gm.volume / C::FUSION_SCALE_DIVISOR).max(C::FUSION_MINIMAL_SCALE);
-        let temp_scale = gm.temperature.log10();
+        let temp_scale = gm.temperature().log10();
 
+        // Bounded below by TOROID_CALCULATED_THRESHOLD - 2 at the fusion temperature gate, so atmos_mod never sees a zero modulus
         let toroidal_size = C::TOROID_CALCULATED_THRESHOLD + {
             if temp_scale <= C::FUSION_BASE_TEMPSCALE {
                 (temp_scale - C::FUSION_BASE_TEMPSCALE) / C::FUSION_BUFFER_DIVISOR
@@ -160,25 +215,7 @@ reaction! (
             }
         };
 
-        let new_e = {
-            if reaction_energy != 0.0 {
-                let middle_energy = {
-                    let alpha = C::FUSION_MOLE_THRESHOLD + C::TOROID_CALCULATED_THRESHOLD * scale_factor / 2.;
-                    let beta = 200. * C::FUSION_MIDDLE_ENERGY_REFERENCE;
-
-                    alpha * beta
-                };
-                let e_alpha = middle_energy * C::FUSION_ENERGY_TRANSLATION_EXPONENT.powf((e / middle_energy).log10());
-                let bowdlerized = reaction_energy
-                    .min(e_alpha * (C::FUSION_ENERGY_TRANSLATION_EXPONENT.powi(2) - 1.))
-                    .max(e_alpha * (C::FUSION_ENERGY_TRANSLATION_EXPONENT.powi(-2) - 1.));
-                middle_energy * 10_f64.powf(((e_alpha + bowdlerized) / middle_energy).log(C::FUSION_ENERGY_TRANSLATION_EXPONENT))
-            } else {
-                e
-            }
-        };
-
-        let released_energy = new_e - e;
+        let released_energy = translate_fusion_energy(e, reaction_energy, scale_factor) - e;
 
         let waste_out = scale_factor * C::FUSION_TRITIUM_CONVERSION_COEFFICIENT * C::FUSION_TRITIUM_MOLES_USED;
 
@@ -202,6 +239,7 @@ reaction! (
     }
 );
 
+// Not conservative: N2 + O2 makes 2 NO2, creating oxygen atoms.
 reaction! (
     called(nitryl_formation)
     with(
@@ -209,13 +247,13 @@ reaction! (
         Gas::O2 => 20.,
         Gas::PlOx => 5.
     )
-    at(temperature!(C::FIRE_MINIMUM_TEMPERATURE_TO_EXIST * 60., K))
+    at(temperature!(C::NITRYL_FORMATION_MIN_TEMPERATURE, K))
     with_gm_as(gm) => {
         let n2 = gm[Gas::N2];
         let o2 = gm[Gas::O2];
-        let t = gm.temperature;
+        let t = gm.temperature();
 
-        let heat_eff = (t / C::FIRE_MINIMUM_TEMPERATURE_TO_EXIST / 60.).min(n2).min(o2);
+        let heat_eff = (t / C::NITRYL_FORMATION_MIN_TEMPERATURE).min(n2).min(o2);
         let energy_use = heat_eff * C::NITRYL_FORMATION_ENERGY;
 
         // Unusual case: nitryl formation doesn't change the heat capacity, but expends energy, so naive delta merge won't work
@@ -230,6 +268,7 @@ reaction! (
     }
 );
 
+// Not conservative: N2O + 2 Pl makes a single BZ, with the oxygen of a balanced reaction split off of it.
 reaction! (
     called(bz_synth)
     with(
@@ -243,29 +282,36 @@ reaction! (
         let n2o = gm[Gas::N2O];
 
         let half_atm_pressure = 2. * p / C::ONE_ATMOSPHERE;
+        // n2o is at least the 10 moles required above, so the ratio stays bounded; at zero pressure the efficiency
+        // is infinite and the usage falls back to the reactants available
         let efficiency = (half_atm_pressure * (pl / n2o).max(1.)).powi(-1);
         let usage = efficiency
             .min(n2o)
             .min(pl / 2.);
-
         let is_balanced = usage == n2o;
 
         let energy_release = 2. * usage * C::FIRE_CARBON_ENERGY_RELEASED;
 
-        let bz_prod = usage - p.max(1.);
+        // A balanced reaction splits oxygen off the BZ, which can exceed the usage at high pressure; never produce negative BZ
+        let bz_prod = if is_balanced {(usage - p.max(1.)).max(0.)} else {usage};
 
-        gm + gen_gas_mix_with_energy!(
-            with(
-                Gas::N2O => -usage,
-                Gas::Pl => -2. * usage,
-                Gas::BZ if is_balanced => bz_prod,
-                Gas::O2 if is_balanced => p.max(1.),
+        if usage <= 0.0 {
+            gm
+        } else {
+            gm + gen_gas_mix_with_energy!(
+                with(
+                    Gas::N2O => -usage,
+                    Gas::Pl => -2. * usage,
+                    Gas::BZ => bz_prod,
+                    Gas::O2 if is_balanced => p.max(1.),
+                )
+                at (energy_release)
             )
-            at (energy_release)
-        )
+        }
     }
 );
 
+// Not conservative: a tenth of a mole of stimulum is made from each mole of plasma, nitryl and tritium.
 reaction! (
     called(stimulum_synth)
     with(
@@ -274,11 +320,11 @@ reaction! (
         Gas::BZ => 20.,
         Gas::NO2 => 30.
     )
-    at(C::STIMULUM_HEAT_SCALE / 2.)
+    at(temperature!(C::STIMULUM_FORMATION_MIN_TEMPERATURE, K))
     with_gm_as(gm) => {
         const COEFFS: [f64; 5] = [1., C::STIMULUM_FIRST_RISE, -C::STIMULUM_FIRST_DROP, C::STIMULUM_SECOND_RISE, -C::STIMULUM_ABSOLUTE_DROP];
 
-        let t = gm.temperature;
+        let t = gm.temperature();
         let pl = gm[Gas::Pl];
         let no2 = gm[Gas::NO2];
         let h2 = gm[Gas::H2];
@@ -298,25 +344,48 @@ reaction! (
     }
 );
 
+// Not conservative: stimulum decays into a mole each of nitrogen and oxygen.
+reaction! (
+    called(stimulum_decay)
+    with_present(
+        Gas::ST
+    )
+    at(temperature!(C::STIMULUM_DECAY_MIN_TEMPERATURE, K))
+    with_gm_as(gm) => {
+        let decayed = gm[Gas::ST] * C::STIMULUM_DECAY_RATE;
+
+        gm + gen_gas_mix_with_energy!(
+            with(
+                Gas::ST => -decayed,
+                Gas::N2 => decayed,
+                Gas::O2 => decayed,
+            )
+            at(decayed * C::STIMULUM_DECAY_ENERGY_RELEASED)
+        )
+    }
+);
+
 reaction! (
     called(hnob_synth)
     with(
         Gas::N2 => 10.,
         Gas::H2 => 5.
     )
-    at(5e6)
+    catalyzed_by(
+        Gas::BZ => bz_efficiency = gm[Gas::BZ].max(1.)
+    )
+    at(temperature!(C::NOBLIUM_FORMATION_MIN_TEMPERATURE, K))
     with_gm_as(gm) => {
         let n2 = gm[Gas::N2];
         let h2 = gm[Gas::H2];
-        let bz = gm[Gas::BZ];
 
         let nob_formed = (0.01 * (n2 + h2)).min(h2 / 10.).min(n2 / 20.);
-        let energy_used = nob_formed * C::NOBLIUM_FORMATION_ENERGY / bz.max(1.);
+        let energy_used = nob_formed * C::NOBLIUM_FORMATION_ENERGY / bz_efficiency;
 
         gm + gen_gas_mix_with_energy!(
             with(
-                Gas::H2 => -10. * nob_formed,
-                Gas::N2 => -20. * nob_formed,
+                Gas::H2 => (-10. * nob_formed).max(-h2),
+                Gas::N2 => (-20. * nob_formed).max(-n2),
                 Gas::HNb => nob_formed,
             )
             at(-energy_used)
@@ -324,21 +393,502 @@ reaction! (
     }
 );
 
+reaction! (
+    called(hnob_decomp)
+    with_present(
+        Gas::HNb
+    )
+    at(temperature!(C::NOBLIUM_DECOMPOSITION_MIN_TEMPERATURE, K))
+    with_gm_as(gm) => {
+        let nob_decomposed = gm[Gas::HNb] * C::NOBLIUM_DECOMPOSITION_RATE;
+
+        gm + gen_gas_mix_with_energy!(
+            with(
+                Gas::HNb => -nob_decomposed,
+                Gas::H2 => 10. * nob_decomposed,
+                Gas::N2 => 20. * nob_decomposed,
+            )
+            at(nob_decomposed * C::NOBLIUM_FORMATION_ENERGY)
+        )
+    }
+);
+
+/// Run every reaction once, in order, each on the previous one's output.
+///
+/// A mixture with `NOBLIUM_SUPPRESSION_THRESHOLD` or more moles of hyper-noblium does not react at all: the check happens once,
+/// before the chain, so noblium suppresses every reaction, including ones that do not involve it.
+/// The one exception is `hnob_decomp`, which runs first so that a hot enough mixture can work its way out of the lock.
+/// Use `react_once_ignore_noblium` to see what such a mixture would do without the suppression.
+///
+/// For any mixture with non-negative amounts of at most 1e8 moles each, a temperature between TCMB and 1e10 K and a positive
+/// volume of at most 1e9 liters, `react_once` does not panic and returns a mixture with finite, non-negative amounts and a finite
+/// temperature of at least TCMB. Far larger inputs can overflow the thermal energy. `fuzz/` holds a target checking this.
 pub fn react_once(gm: GasMixture) -> GasMixture {
     if verify_hnob(&gm) {
-        chained_call! (
-            gm =>
-            n2o_decomp =>
-            trit_fire =>
-            plasma_fire =>
-            fusion =>
-            nitryl_formation =>
-            bz_synth =>
-            stimulum_synth =>
-            hnob_synth
-        )
+        react_once_ignore_noblium(gm)
+    } else {
+        hnob_decomp(gm)
+    }
+}
+
+standard_reactions!(
+    hnob_decomp,
+    n2o_decomp,
+    trit_fire,
+    plasma_fire,
+    fusion,
+    nitryl_formation,
+    bz_synth,
+    stimulum_synth,
+    stimulum_decay,
+    hnob_synth,
+);
+
+pub type Reaction = fn(GasMixture) -> GasMixture;
+
+/// Reaction supplied at runtime, such as a closure registered by a mod.
+pub type CustomReaction = Box<dyn Fn(GasMixture) -> GasMixture + Send + Sync>;
+
+type SharedReaction = Arc<dyn Fn(GasMixture) -> GasMixture + Send + Sync>;
+
+/// Reactions run by a `ReactionSet`, highest priority first and in insertion order among equal priorities.
+/// Standard reactions keep their `raw` output alongside, for `react_once_audited` to check before rounding.
+#[derive(Clone, Default)]
+pub struct ReactionSet {
+    reactions: Vec<(&'static str, i32, SharedReaction, Option<Reaction>)>,
+}
+
+/// Declarative gate of a reaction, as written in its `reaction!` invocation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReactionSpec {
+    pub name: &'static str,
+    pub requirements: &'static [(Gas, f64)],
+    /// Gases in `requirements` that only need to be there, at `MINIMUM_MOLE_COUNT` unless presence is judged at
+    /// another amount as in `react_once_with_min_moles`; every other requirement is a fixed threshold.
+    pub present: &'static [Gas],
+    /// Partial pressures the gases must reach on top of `requirements`. None of the standard reactions set any: TG gates
+    /// them all on moles, and `bz_synth`, the only one that depends on pressure, scales its efficiency with it instead.
+    pub partial_pressure_requirements: &'static [(Gas, f64)],
+    /// Gases that influence the reaction without being consumed or produced by it.
+    pub catalysts: &'static [Gas],
+    pub min_temperature: f64,
+    /// Total pressure below which the reaction does not run, negative infinity for most reactions.
+    /// None of the standard reactions set it: like TG's, they are all gated on temperature and moles alone.
+    pub min_pressure: f64,
+}
+
+impl ReactionSpec {
+    pub fn requirements_met(&self, gm: &GasMixture) -> bool {
+        self.requirements.iter().all(|(gas, amount)| gm[*gas] >= *amount)
+            && self
+                .partial_pressure_requirements
+                .iter()
+                .all(|(gas, pressure)| gm.partial_pressure(*gas) >= *pressure)
+            && (self.min_pressure == f64::NEG_INFINITY || gm.get_pressure() >= self.min_pressure)
+    }
+
+    /// Whether the reaction's gate is open for `gm`; it may still turn out to have no effect.
+    pub fn can_react(&self, gm: &GasMixture) -> bool {
+        gm.temperature() >= self.min_temperature && self.requirements_met(gm)
+    }
+
+    /// Why the reaction's gate is shut for `gm`, checking temperature, then total pressure, then gases,
+    /// or `None` if it is open. Hyper-noblium suppression is not part of the gate and is not reported.
+    pub fn blocked_by(&self, gm: &GasMixture) -> Option<ReactionStatus> {
+        let short_gas = self
+            .requirements
+            .iter()
+            .find(|(gas, amount)| gm[*gas] < *amount)
+            .or_else(|| {
+                self.partial_pressure_requirements
+                    .iter()
+                    .find(|(gas, pressure)| gm.partial_pressure(*gas) < *pressure)
+            });
+
+        if gm.temperature() < self.min_temperature {
+            Some(ReactionStatus::BelowTemperature {
+                need: self.min_temperature,
+                have: gm.temperature(),
+            })
+        } else if gm.get_pressure() < self.min_pressure {
+            Some(ReactionStatus::BelowPressure {
+                need: self.min_pressure,
+                have: gm.get_pressure(),
+            })
+        } else {
+            short_gas.map(|(gas, _)| ReactionStatus::InsufficientGas(*gas))
+        }
+    }
+}
+
+/// Specs of the reactions that count as a fire.
+pub const FIRE_REACTION_SPECS: &[ReactionSpec] = &[trit_fire::SPEC, plasma_fire::SPEC];
+
+pub fn reaction_specs() -> Vec<ReactionSpec> {
+    STANDARD_REACTION_SPECS.to_vec()
+}
+
+/// Whether `reaction` still runs on a mixture whose other reactions are suppressed by hyper-noblium.
+fn bypasses_noblium_lock(reaction: &str) -> bool {
+    reaction == hnob_decomp::SPEC.name
+}
+
+/// Leading part of `STANDARD_REACTIONS` that `react_once` runs on `gm`: all of it, or only `hnob_decomp` under the noblium lock.
+pub(crate) fn active_reactions(gm: &GasMixture) -> &'static [(&'static str, Reaction)] {
+    if verify_hnob(gm) {
+        STANDARD_REACTIONS
+    } else {
+        &STANDARD_REACTIONS[..1]
+    }
+}
+
+/// Specs of `active_reactions(gm)`.
+pub(crate) fn active_reaction_specs(gm: &GasMixture) -> &'static [ReactionSpec] {
+    &STANDARD_REACTION_SPECS[..active_reactions(gm).len()]
+}
+
+pub type GatedReaction = fn(GasMixture, f64, f64) -> GasMixture;
+
+/// React once like `react_once`, but judging the presence of gases at `min_moles` instead of `MINIMUM_MOLE_COUNT`,
+/// so lower thresholds let reactions run on smaller amounts. Traces below `min_moles` are pruned from the result.
+pub fn react_once_with_min_moles(gm: GasMixture, min_moles: f64) -> GasMixture {
+    let mut result = active_reaction_specs(&gm)
+        .iter()
+        .zip(STANDARD_GATED_REACTIONS)
+        .fold(gm, |cur, (spec, reaction)| reaction(cur, spec.min_temperature, min_moles));
+    result.prune_traces(min_moles);
+
+    result
+}
+
+/// State carried from one tick of a mixture to the next to keep reactions from flickering at their temperature gate.
+/// A reaction whose gate was open on the previous tick stays open until the temperature drops `hysteresis` kelvin
+/// below it, `REACTION_HYSTERESIS_BAND` by default.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReactionContext {
+    pub hysteresis: f64,
+    active: Vec<&'static str>,
+}
+
+impl Default for ReactionContext {
+    fn default() -> Self {
+        Self::new(C::REACTION_HYSTERESIS_BAND)
+    }
+}
+
+impl ReactionContext {
+    pub fn new(hysteresis: f64) -> Self {
+        ReactionContext {
+            hysteresis,
+            active: Vec::new(),
+        }
+    }
+
+    /// Reactions whose gate was open on the last tick, in the order they ran.
+    pub fn active(&self) -> &[&'static str] {
+        &self.active
+    }
+}
+
+/// React once like `react_once`, but with the temperature gates of the reactions active on the previous tick lowered
+/// by the context's hysteresis band. The context is updated with the reactions whose gate was open this tick.
+pub fn react_once_with_context(gm: GasMixture, context: &mut ReactionContext) -> GasMixture {
+    let mut active = Vec::new();
+    let result = active_reaction_specs(&gm)
+        .iter()
+        .zip(STANDARD_GATED_REACTIONS)
+        .fold(gm, |cur, (spec, reaction)| {
+            let min_temperature = if context.active.contains(&spec.name) {
+                spec.min_temperature - context.hysteresis
+            } else {
+                spec.min_temperature
+            };
+            if cur.temperature() >= min_temperature && spec.requirements_met(&cur) {
+                active.push(spec.name);
+            }
+            reaction(cur, min_temperature, C::MINIMUM_MOLE_COUNT)
+        });
+    context.active = active;
+
+    result
+}
+
+/// Minimum temperatures of the standard reactions in ascending order, alongside the matching reaction names.
+static TEMPERATURE_INDEX: OnceLock<(Vec<f64>, Vec<&'static str>)> = OnceLock::new();
+
+/// Names of the standard reactions whose temperature gate is open at `temperature`, coldest gate first.
+/// Only the thermal gate is considered; the reactions may still lack the gases they need.
+pub fn reactions_possible_at(temperature: f64) -> &'static [&'static str] {
+    let (temperatures, names) = TEMPERATURE_INDEX.get_or_init(|| {
+        let mut specs = reaction_specs();
+        specs.sort_by(|lhs, rhs| lhs.min_temperature.total_cmp(&rhs.min_temperature));
+        specs
+            .iter()
+            .map(|spec| (spec.min_temperature, spec.name))
+            .unzip()
+    });
+
+    &names[..temperatures.partition_point(|min_temperature| *min_temperature <= temperature)]
+}
+
+impl ReactionSet {
+    /// The standard reactions in their usual order, all at priority 0.
+    pub fn standard() -> Self {
+        let mut set = ReactionSet::default();
+        for ((name, reaction), raw) in STANDARD_REACTIONS.iter().zip(STANDARD_RAW_REACTIONS) {
+            set.insert(name, 0, Arc::new(*reaction), Some(*raw));
+        }
+        set
+    }
+
+    /// Add a reaction at priority 0.
+    pub fn with(mut self, name: &'static str, reaction: Reaction) -> Self {
+        self.insert(name, 0, Arc::new(reaction), None);
+        self
+    }
+
+    /// Add a runtime-defined reaction, run before every reaction of lower `priority` and after those of equal or higher.
+    pub fn with_custom(&mut self, name: &'static str, priority: i32, f: CustomReaction) -> &mut Self {
+        self.insert(name, priority, Arc::from(f), None);
+        self
+    }
+
+    fn insert(&mut self, name: &'static str, priority: i32, reaction: SharedReaction, raw: Option<Reaction>) {
+        let index = self
+            .reactions
+            .iter()
+            .position(|(_, other_priority, _, _)| *other_priority < priority)
+            .unwrap_or(self.reactions.len());
+        self.reactions.insert(index, (name, priority, reaction, raw));
+    }
+
+    /// Names of the reactions in the order they run.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.reactions.iter().map(|(name, _, _, _)| *name).collect()
+    }
+
+    /// Names of the reactions in the order `react_once` runs them, for checking custom priorities resolved as intended.
+    /// Reactions are kept sorted as they are added, so this is the same order as `names`; the noblium lock may still skip some.
+    pub fn resolved_order(&self) -> Vec<&'static str> {
+        self.names()
+    }
+
+    pub fn react_once(&self, gm: GasMixture) -> GasMixture {
+        let unlocked = verify_hnob(&gm);
+        self.reactions
+            .iter()
+            .filter(|(name, _, _, _)| unlocked || bypasses_noblium_lock(name))
+            .fold(gm, |cur, (_, _, reaction, _)| reaction(cur))
+    }
+
+    /// React once like `react_once`, in debug builds asserting after every reaction that no gas decreased by more than
+    /// the reaction started with. Standard reactions are checked on their `raw` output, so an overdraw small enough to be
+    /// cleared as a rounding negative is still caught; runtime-defined reactions are checked on what they return.
+    pub fn react_once_audited(&self, gm: GasMixture) -> GasMixture {
+        let unlocked = verify_hnob(&gm);
+        self.reactions
+            .iter()
+            .filter(|(name, _, _, _)| unlocked || bypasses_noblium_lock(name))
+            .fold(gm, |cur, (name, _, reaction, raw)| {
+                let next = reaction(cur);
+                let unrounded = raw.map_or(next, |raw| raw(cur));
+                for (gas, amount) in unrounded.gases.0.iter() {
+                    let used = cur[gas] - amount;
+                    debug_assert!(
+                        used <= cur[gas],
+                        "{} used {} moles of {:?} with only {} available",
+                        name,
+                        used,
+                        gas,
+                        cur[gas]
+                    );
+                }
+                next
+            })
+    }
+
+    /// Run the set on `sample` for up to `max_iters` ticks.
+    /// Returns `None` if the mixture stabilizes, otherwise the tick at which an earlier state recurred, or `max_iters` if it never settled.
+    pub fn detect_nonconvergence(&self, sample: &GasMixture, max_iters: usize) -> Option<usize> {
+        let mut seen = vec![*sample];
+        let mut cur = *sample;
+
+        for i in 1..=max_iters {
+            let next = self.react_once(cur);
+            if next == cur {
+                return None;
+            }
+            if seen.contains(&next) {
+                return Some(i);
+            }
+            seen.push(next);
+            cur = next;
+        }
+
+        Some(max_iters)
+    }
+}
+
+/// Move `before` a `factor` of the way towards `after`, interpolating both the gases and the thermal energy.
+pub fn scale_reaction_step(before: GasMixture, after: GasMixture, factor: f64) -> GasMixture {
+    extend_reaction_step(before, after, factor.clamp(0., 1.))
+}
+
+fn extend_reaction_step(before: GasMixture, after: GasMixture, factor: f64) -> GasMixture {
+    if factor == 1.0 || before == after {
+        return after;
+    } else if factor == 0.0 {
+        return before;
+    }
+
+    let gases = before.gases + (after.gases - before.gases) * factor;
+    if gases.get_heat_cap() == 0.0 {
+        // Nothing to hold the energy, so keep the temperature it started at
+        return GasMixture::new(gases, before.temperature(), before.volume);
+    }
+
+    let before_energy = before.get_energy();
+    GasMixture::with_energy(
+        gases,
+        before_energy + (after.get_energy() - before_energy) * factor,
+        before.volume,
+    )
+}
+
+/// Largest factor `before` can be moved along its step towards `after` without consuming more of any gas than it has.
+fn max_step_factor(before: &GasMixture, after: &GasMixture) -> f64 {
+    before
+        .gases
+        .0
+        .iter()
+        .filter(|(g, amount)| after[*g] < **amount)
+        .map(|(g, amount)| amount / (amount - after[g]))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// React once running only the named reactions, in their usual order and subject to the same noblium lock as `react_once`.
+/// Names that match no standard reaction are ignored.
+pub fn react_only(gm: GasMixture, reactions: &[&str]) -> GasMixture {
+    active_reactions(&gm)
+        .iter()
+        .filter(|(name, _)| reactions.contains(name))
+        .fold(gm, |cur, (_, reaction)| reaction(cur))
+}
+
+/// React once like `react_once`, in debug builds asserting that no reaction used more of a gas than was available to it.
+///
+/// How each standard reaction stays within the gas it has:
+/// - `fusion` relies on its declarative `with` limit for tritium, using exactly the `FUSION_TRITIUM_MOLES_USED` it
+///   requires, and on internal `max(-amount)` guards for plasma and carbon dioxide, whose thresholds do not bound the change.
+/// - `plasma_fire`, `nitryl_formation`, `bz_synth`, `stimulum_synth` and `hnob_synth` take the `min` of their reactants,
+///   so their `with` thresholds only decide whether they run. Scaling the `min` back up can overshoot by a rounding error,
+///   so `plasma_fire`'s oxygen and `hnob_synth`'s tritium and nitrogen are also guarded with `max(-amount)`.
+/// - `n2o_decomp`, `stimulum_decay` and `hnob_decomp` use at most a fixed fraction of their single reactant.
+/// - `trit_fire` picks its branch by which of tritium and oxygen runs out first. Below `MINIMUM_HEAT_CAPACITY` of thermal
+///   energy it burns a hundredth of the oxygen as tritium whatever the tritium, as TG does, which can overdraw it.
+pub fn react_once_audited(gm: GasMixture) -> GasMixture {
+    ReactionSet::standard().react_once_audited(gm)
+}
+
+/// Change in gases the named standard reaction alone would make to `gm` this tick, or `None` for an unknown name.
+pub fn reaction_delta(name: &str, gm: &GasMixture) -> Option<GasVec> {
+    STANDARD_REACTIONS
+        .iter()
+        .find(|(reaction_name, _)| *reaction_name == name)
+        .map(|(_, reaction)| reaction(*gm).gases - gm.gases)
+}
+
+/// Net moles per tick of the `forward` reaction's main product when it and `reverse` both act on `gm`: positive when
+/// the pair is net producing it, negative when net consuming it. The main product is the gas `forward` would produce
+/// the most of, and an unknown name counts as a reaction that does not fire.
+pub fn net_direction(forward: &str, reverse: &str, gm: &GasMixture) -> f64 {
+    let forward_delta = reaction_delta(forward, gm).unwrap_or(gen_gas_vec!());
+    let reverse_delta = reaction_delta(reverse, gm).unwrap_or(gen_gas_vec!());
+
+    let product = forward_delta
+        .0
+        .iter()
+        .filter(|(_, amount)| **amount > 0.0)
+        .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+        .map(|(gas, _)| gas);
+
+    product.map_or(0.0, |gas| forward_delta[gas] + reverse_delta[gas])
+}
+
+/// React once with every reaction's extent multiplied by `rate_multiplier`.
+/// This is a balance knob for server reaction speed rather than a timestep: extents scale linearly, clamped so no reaction consumes more gas than is present.
+pub fn react_once_rate(gm: GasMixture, rate_multiplier: f64) -> GasMixture {
+    if rate_multiplier <= 0.0 {
+        return gm;
+    }
+
+    active_reactions(&gm).iter().fold(gm, |cur, (_, reaction)| {
+        let next = reaction(cur);
+        extend_reaction_step(cur, next, rate_multiplier.min(max_step_factor(&cur, &next)))
+    })
+}
+
+/// React once, releasing at most `cap` joules of heat.
+/// Reactions run in their usual order and draw from the remaining budget; the one that exhausts it is scaled down and the rest are skipped.
+pub fn react_once_energy_capped(gm: GasMixture, cap: f64) -> GasMixture {
+    let mut budget = cap;
+    let mut cur = gm;
+    for (_, reaction) in active_reactions(&gm) {
+        if budget <= 0.0 {
+            break;
+        }
+
+        let next = reaction(cur);
+        let released = next.get_energy() - cur.get_energy();
+        if released > budget {
+            cur = scale_reaction_step(cur, next, budget / released);
+            budget = 0.0;
+        } else {
+            cur = next;
+            budget -= released.max(0.0);
+        }
+    }
+
+    cur
+}
+
+/// React once with every reaction evaluated against the tick-start mixture, SS13-style, instead of feeding each reaction the previous one's output.
+/// When the reactions together would consume more of a gas than is available, each reaction is scaled down by its most oversubscribed reactant.
+pub fn react_once_simultaneous(gm: GasMixture) -> GasMixture {
+    let initial_energy = gm.get_energy();
+    let steps: Vec<(GasVec, f64)> = active_reactions(&gm)
+        .iter()
+        .map(|(_, reaction)| reaction(gm))
+        .filter(|next| *next != gm)
+        .map(|next| (next.gases - gm.gases, next.get_energy() - initial_energy))
+        .collect();
+    if steps.is_empty() {
+        return gm;
+    }
+
+    let consumed = steps
+        .iter()
+        .fold(gen_gas_vec!(), |total, (delta, _)| {
+            total + GasVec(GasEnumMap::from(|g| (-delta[g]).max(0.)))
+        });
+
+    let (gases, energy) = steps
+        .iter()
+        .fold((gm.gases, initial_energy), |(gases, energy), (delta, released)| {
+            let factor = delta
+                .0
+                .iter()
+                .filter(|(_, amount)| **amount < 0.)
+                .map(|(g, _)| (gm[g] / consumed[g]).min(1.))
+                .fold(1., f64::min);
+
+            (gases + *delta * factor, energy + released * factor)
+        });
+
+    if gases.get_heat_cap() == 0.0 {
+        GasMixture::new(gases, gm.temperature(), gm.volume)
     } else {
-        gm
+        GasMixture::with_energy(gases, energy, gm.volume)
     }
 }
 
@@ -353,11 +903,348 @@ pub fn react_several(gm: GasMixture, times: usize) -> Vec<GasMixture> {
     result
 }
 
+/// React `ticks` times like `react_several`, injecting gas along the way as a feed valve would.
+/// Each `(tick, gas, moles, temperature)` entry adds `moles` of `gas` at `temperature` right before the reaction
+/// of the zero-based `tick`, mixing in its heat and leaving the volume unchanged. Entries past the last tick are ignored.
+pub fn react_with_schedule(gm: GasMixture, schedule: &[(usize, Gas, f64, f64)], ticks: usize) -> Vec<GasMixture> {
+    let mut result = Vec::with_capacity(ticks);
+    let mut cur = gm;
+    for tick in 0..ticks {
+        for &(_, gas, moles, temperature) in schedule.iter().filter(|entry| entry.0 == tick) {
+            GasSource::merge(&mut cur, GasMixture::new(GasVec::single(gas, moles), temperature, 0.0));
+        }
+        cur = react_once(cur);
+        result.push(cur);
+    }
+
+    result
+}
+
+/// React once, scaling down any reaction whose production of `gas` would push it above `max_moles`.
+/// Reactions producing the same gas share the remaining headroom in their usual order.
+pub fn react_once_capped_product(gm: GasMixture, gas: Gas, max_moles: f64) -> GasMixture {
+    active_reactions(&gm).iter().fold(gm, |cur, (_, reaction)| {
+        let next = reaction(cur);
+        let produced = next[gas] - cur[gas];
+        if produced > 0.0 && next[gas] > max_moles {
+            scale_reaction_step(cur, next, (max_moles - cur[gas]) / produced)
+        } else {
+            next
+        }
+    })
+}
+
+/// React once, scaling down any reaction that would take the temperature more than `max_temp_delta` away from
+/// the tick-start temperature, so an explosive tick cannot overshoot when coupled to another numerical integrator.
+/// Reactions keep their usual order; the one crossing the limit is scaled to end exactly on it.
+pub fn react_once_max_dt(gm: GasMixture, max_temp_delta: f64) -> GasMixture {
+    let max_temp_delta = max_temp_delta.max(0.0);
+    let (low, high) = (gm.temperature() - max_temp_delta, gm.temperature() + max_temp_delta);
+
+    active_reactions(&gm).iter().fold(gm, |cur, (_, reaction)| {
+        let next = reaction(cur);
+        let target = next.temperature().clamp(low, high);
+        // A reaction that did nothing is kept even if rounding left the mixture a hair past the limit
+        if target == next.temperature() || next == cur {
+            return next;
+        }
+
+        // Gases and energy move linearly along the step, so the temperature reaches the target at a closed-form factor
+        let (cap, energy) = (cur.get_heat_cap(), cur.get_energy());
+        let factor = (target * cap - energy) / ((next.get_energy() - energy) - target * (next.get_heat_cap() - cap));
+        scale_reaction_step(cur, next, factor)
+    })
+}
+
+/// React once after a heater or freezer has put `heat_input` joules into the mixture, negative to take heat out.
+/// The heat is applied before reacting, so it can drive the mixture into ignition within the same tick;
+/// the temperature is floored at TCMB, and a mixture without heat capacity is left unheated.
+pub fn react_once_with_heat(gm: GasMixture, heat_input: f64) -> GasMixture {
+    if gm.get_heat_cap() > 0.0 {
+        react_once(gm.adjust_thermal_energy(heat_input))
+    } else {
+        react_once(gm)
+    }
+}
+
+/// React once, then put back the gases in `locked` to their pre-reaction amounts, as if an ideal reservoir held them steady.
+/// Restored gas comes back at the post-reaction temperature, carrying its heat with it. This is an analysis mode for
+/// studying one part of a reaction in isolation, such as fuel consumption at a fixed oxygen level, and by design
+/// conserves neither moles nor energy.
+pub fn react_once_with_locked(gm: GasMixture, locked: &[Gas]) -> GasMixture {
+    locked
+        .iter()
+        .fold(react_once(gm), |cur, &gas| cur.with_gas(gas, gm[gas] - cur[gas]))
+}
+
+/// React once as if the mixture were perfectly heat-sinked, returning the products at the pre-reaction temperature
+/// together with the heat the sink had to absorb to keep them there. Endothermic ticks report a negative heat.
+pub fn react_once_isothermal(gm: GasMixture) -> (GasMixture, f64) {
+    let reacted = react_once(gm);
+    let mut result = reacted;
+    result.set_temperature(gm.temperature());
+
+    (result, reacted.get_energy() - result.get_energy())
+}
+
+/// React once in a chamber that cannot get hotter than `max_temp`: any heat above it goes to a heat sink instead.
+/// Returns the reacted mixture, clamped to `max_temp` if it overshot, and the energy dumped to the sink to get there.
+pub fn react_once_temp_capped(gm: GasMixture, max_temp: f64) -> (GasMixture, f64) {
+    let reacted = react_once(gm);
+    if reacted.temperature() <= max_temp {
+        return (reacted, 0.0);
+    }
+
+    let capped = reacted.clone_at_temperature(max_temp);
+    (capped, reacted.get_energy() - capped.get_energy())
+}
+
+/// Effect of a single reaction that fired during a traced tick.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReactionOutcome {
+    pub name: &'static str,
+    pub delta: GasVec,
+    pub energy_released: f64,
+}
+
+/// React once like `react_once`, also reporting the effect of every reaction that changed the mixture, in order.
+pub fn react_once_traced(gm: GasMixture) -> (GasMixture, Vec<ReactionOutcome>) {
+    let mut outcomes = Vec::new();
+    let result = active_reactions(&gm).iter().fold(gm, |cur, (name, reaction)| {
+        let next = reaction(cur);
+        if next != cur {
+            outcomes.push(ReactionOutcome {
+                name,
+                delta: next.gases - cur.gases,
+                energy_released: next.get_energy() - cur.get_energy(),
+            });
+        }
+        next
+    });
+
+    (result, outcomes)
+}
+
+/// Radiation pulsed by a traced reaction. Only burning tritium is irradiating here; fusion's radiation depends on
+/// its instability, which a `ReactionOutcome` does not record.
+pub fn radiation_released(outcome: &ReactionOutcome) -> f64 {
+    if outcome.name == trit_fire::SPEC.name
+        && outcome.energy_released > C::TRITIUM_MINIMUM_RADIATION_ENERGY
+    {
+        outcome.energy_released / C::TRITIUM_BURN_RADIOACTIVITY_FACTOR
+    } else {
+        0.0
+    }
+}
+
+/// Why a reaction did or did not change the mixture during a tick, as reported by `react_once_explained`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ReactionStatus {
+    Fired,
+    BelowTemperature { need: f64, have: f64 },
+    BelowPressure { need: f64, have: f64 },
+    /// The first gas whose amount or partial pressure fell short of the reaction's requirements.
+    InsufficientGas(Gas),
+    NobliumSuppressed,
+    /// The gate was open but the reaction left the mixture unchanged.
+    NoEffect,
+}
+
+/// React once like `react_once`, also explaining for every standard reaction, in order, whether it fired and why not.
+/// Each reaction is judged on the mixture left by the ones before it.
+pub fn react_once_explained(gm: GasMixture) -> (GasMixture, Vec<(&'static str, ReactionStatus)>) {
+    let active = active_reactions(&gm).len();
+    let mut explanation = Vec::with_capacity(STANDARD_REACTIONS.len());
+    let mut cur = gm;
+
+    for (i, ((name, reaction), spec)) in STANDARD_REACTIONS.iter().zip(STANDARD_REACTION_SPECS).enumerate() {
+        let status = if i >= active {
+            ReactionStatus::NobliumSuppressed
+        } else if let Some(status) = spec.blocked_by(&cur) {
+            status
+        } else {
+            let next = reaction(cur);
+            let status = if next != cur {
+                ReactionStatus::Fired
+            } else {
+                ReactionStatus::NoEffect
+            };
+            cur = next;
+            status
+        };
+        explanation.push((*name, status));
+    }
+
+    (cur, explanation)
+}
+
+/// Recording of the reactions of one or more ticks, to be replayed later as a golden trace.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReactionLog {
+    pub outcomes: Vec<ReactionOutcome>,
+}
+
+impl ReactionLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reconstruct the final state of the recorded run by applying every recorded delta to `initial`.
+    /// `initial` must be the mixture the recording started from; replaying onto any other mixture is unsupported
+    /// and gives meaningless results, since the deltas are not re-derived from the reactions.
+    pub fn replay(&self, initial: GasMixture) -> GasMixture {
+        self.outcomes
+            .iter()
+            .fold(initial, |cur, outcome| cur.apply_delta(outcome))
+    }
+}
+
+/// React once like `react_once`, appending the effect of every reaction that fired to `log`.
+pub fn react_once_logged(gm: GasMixture, log: &mut ReactionLog) -> GasMixture {
+    let (result, outcomes) = react_once_traced(gm);
+    log.outcomes.extend(outcomes);
+
+    result
+}
+
+/// Threshold crossed during a single tick, as reported by `react_once_events`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReactionEvent {
+    /// A mixture that could not burn can now sustain a fire.
+    Ignition,
+    /// A mixture that could burn no longer can.
+    Extinguished,
+    /// The temperature rose past `FUSION_TEMPERATURE_THRESHOLD`.
+    FusionCascade,
+    /// Hyper-noblium rose past `NOBLIUM_SUPPRESSION_THRESHOLD`, locking out further reactions.
+    NobliumFormed,
+    /// The pressure rose past `TANK_FRAGMENT_PRESSURE`.
+    Detonation,
+}
+
+/// Whether a fire reaction's gate is open for `gm` and not suppressed by hyper-noblium.
+fn is_burning(gm: &GasMixture) -> bool {
+    verify_hnob(gm) && FIRE_REACTION_SPECS.iter().any(|spec| spec.can_react(gm))
+}
+
+/// React once like `react_once`, also reporting every threshold the mixture crossed, comparing its state before and after the tick.
+/// Events are only emitted on the tick a threshold is crossed, not for as long as the mixture stays past it.
+pub fn react_once_events(gm: GasMixture) -> (GasMixture, Vec<ReactionEvent>) {
+    let result = react_once(gm);
+    (result, events_between(&gm, &result))
+}
+
+/// Thresholds crossed going from `gm` to `result`, in the order of `ReactionEvent`.
+pub(crate) fn events_between(gm: &GasMixture, result: &GasMixture) -> Vec<ReactionEvent> {
+    let rose_past = |before: f64, after: f64, threshold: f64| before < threshold && after >= threshold;
+
+    let mut events = Vec::new();
+    match (is_burning(gm), is_burning(result)) {
+        (false, true) => events.push(ReactionEvent::Ignition),
+        (true, false) => events.push(ReactionEvent::Extinguished),
+        _ => {}
+    }
+    if rose_past(gm.temperature(), result.temperature(), C::FUSION_TEMPERATURE_THRESHOLD) {
+        events.push(ReactionEvent::FusionCascade);
+    }
+    if rose_past(gm[Gas::HNb], result[Gas::HNb], C::NOBLIUM_SUPPRESSION_THRESHOLD) {
+        events.push(ReactionEvent::NobliumFormed);
+    }
+    if rose_past(gm.get_pressure(), result.get_pressure(), C::TANK_FRAGMENT_PRESSURE) {
+        events.push(ReactionEvent::Detonation);
+    }
+
+    events
+}
+
+/// React until done like `react_until_done`, also counting how many ticks each reaction fired on.
+pub fn react_until_done_stats(gm: GasMixture) -> (GasMixture, HashMap<&'static str, u32>) {
+    let mut counts = HashMap::new();
+    let mut cur = gm;
+
+    while !cur.is_stable() {
+        let (next, outcomes) = react_once_traced(cur);
+        for outcome in outcomes {
+            *counts.entry(outcome.name).or_insert(0) += 1;
+        }
+        let done = settled(&cur, &next);
+        cur = next;
+        if done {
+            break;
+        }
+    }
+
+    (cur, counts)
+}
+
+/// React several times like `react_several`, also totalling the thermal energy each reaction released over all ticks.
+/// Endothermic reactions contribute negative totals, so the values sum to the overall change in thermal energy.
+pub fn react_several_energy_by_reaction(
+    gm: GasMixture,
+    times: usize,
+) -> (Vec<GasMixture>, HashMap<&'static str, f64>) {
+    let mut result = Vec::with_capacity(times);
+    let mut energies = HashMap::new();
+    let mut cur = gm;
+    for _ in 1..=times {
+        let (next, outcomes) = react_once_traced(cur);
+        for outcome in outcomes {
+            *energies.entry(outcome.name).or_insert(0.0) += outcome.energy_released;
+        }
+        cur = next;
+        result.push(cur);
+    }
+
+    (result, energies)
+}
+
+/// Endless iterator over the successive reaction results of `gm`, starting with the first reaction.
+pub fn react_iter(gm: GasMixture) -> impl Iterator<Item = GasMixture> {
+    std::iter::successors(Some(react_once(gm)), |cur| Some(react_once(*cur)))
+}
+
+/// Find the first tick within `ticks` at which the temperature exceeds `threshold_k`.
+/// The result indexes into the output of `react_several(gm, ticks)`.
+pub fn detect_runaway(gm: GasMixture, ticks: usize, threshold_k: f64) -> Option<usize> {
+    react_iter(gm)
+        .take(ticks)
+        .position(|cur| cur.temperature() > threshold_k)
+}
+
+/// React `ticks.floor()` times, then move the fractional remainder of the way towards the next tick.
+/// This is only a linear interpolation for smooth presentation, not a physical sub-step.
+pub fn react_fractional(gm: GasMixture, ticks: f64) -> GasMixture {
+    let ticks = ticks.max(0.);
+    let whole = react_iter(gm)
+        .take(ticks.floor() as usize)
+        .last()
+        .unwrap_or(gm);
+
+    scale_reaction_step(whole, react_once(whole), ticks.fract())
+}
+
+/// Whether a tick from `prev` to `next` changed the mixture by less than `REACTION_SETTLE_TOLERANCE`.
+/// Comparing exactly would make the number of ticks, and so the result, depend on the last bits of libm's output.
+fn settled(prev: &GasMixture, next: &GasMixture) -> bool {
+    prev.approx_equals(next, C::REACTION_SETTLE_TOLERANCE)
+}
+
+/// React until a tick changes the mixture by less than `REACTION_SETTLE_TOLERANCE`.
+/// Reactions using transcendental functions, fusion in particular, may differ in their last bits between platforms,
+/// so results are reproducible across platforms only to within a small relative tolerance, not bit for bit.
+///
+/// The result is a fixed point of `react_once` to within `REACTION_SETTLE_TOLERANCE`: reacting it again moves it by
+/// no more than that. It is an exact fixed point when it `is_stable`; a reaction that only approaches its limit,
+/// such as N2O decomposition nearing its temperature cap, can leave it settled but still creeping in the last bits.
 pub fn react_until_done(gm: GasMixture) -> GasMixture {
+    if gm.is_stable() {
+        return gm;
+    }
+
     let mut prev_gm = gm;
     let mut next_gm = react_once(gm);
 
-    while prev_gm != next_gm {
+    while !settled(&prev_gm, &next_gm) {
         prev_gm = next_gm;
         next_gm = react_once(next_gm);
     }
@@ -365,6 +1252,34 @@ pub fn react_until_done(gm: GasMixture) -> GasMixture {
     next_gm
 }
 
+/// Ticks `react_until_done_timed` runs between reads of the clock.
+const TIMED_CLOCK_INTERVAL: usize = 64;
+
+/// React until done like `react_until_done`, giving up once `budget` has elapsed.
+/// Returns the last mixture reached and whether it had converged. The clock is only read every
+/// `TIMED_CLOCK_INTERVAL` ticks, so the budget can be overrun by that many ticks.
+pub fn react_until_done_timed(gm: GasMixture, budget: Duration) -> (GasMixture, bool) {
+    if gm.is_stable() {
+        return (gm, true);
+    }
+
+    let start = Instant::now();
+    let mut prev_gm = gm;
+    let mut next_gm = react_once(gm);
+    let mut ticks = 1;
+
+    while !settled(&prev_gm, &next_gm) {
+        if ticks % TIMED_CLOCK_INTERVAL == 0 && start.elapsed() >= budget {
+            return (next_gm, false);
+        }
+        prev_gm = next_gm;
+        next_gm = react_once(next_gm);
+        ticks += 1;
+    }
+
+    (next_gm, true)
+}
+
 pub fn react_each_once(gms: Vec<GasMixture>) -> Vec<GasMixture> {
     gms.iter().map(|gm| react_once(*gm)).collect()
 }
@@ -376,3 +1291,62 @@ pub fn react_each_several(gms: Vec<GasMixture>, times: usize) -> Vec<Vec<GasMixt
 pub fn react_each_until_done(gms: Vec<GasMixture>) -> Vec<GasMixture> {
     gms.iter().map(|gm| react_until_done(*gm)).collect()
 }
+
+/// Convergence of a batch reacted by `react_each_until_done_stats`, in ticks.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BatchStats {
+    pub min_ticks: usize,
+    pub max_ticks: usize,
+    pub mean_ticks: f64,
+    /// Mixtures still changing after `BATCH_MAX_TICKS` ticks.
+    pub capped: usize,
+}
+
+/// React each mixture until a tick changes it by less than `epsilon`, relative as in `GasMixture::approx_equals`,
+/// or for at most `BATCH_MAX_TICKS` ticks, also reporting how many ticks the mixtures took.
+/// Mixtures that are already stable take zero ticks.
+pub fn react_each_until_done_stats(gms: Vec<GasMixture>, epsilon: f64) -> (Vec<GasMixture>, BatchStats) {
+    let mut results = Vec::with_capacity(gms.len());
+    let mut stats = BatchStats {
+        min_ticks: usize::MAX,
+        ..BatchStats::default()
+    };
+    let mut total_ticks = 0;
+
+    for gm in gms {
+        let (result, ticks, settled) = react_until_settled(gm, epsilon, C::BATCH_MAX_TICKS);
+        results.push(result);
+        stats.min_ticks = stats.min_ticks.min(ticks);
+        stats.max_ticks = stats.max_ticks.max(ticks);
+        total_ticks += ticks;
+        if !settled {
+            stats.capped += 1;
+        }
+    }
+
+    if results.is_empty() {
+        return (results, BatchStats::default());
+    }
+    stats.mean_ticks = total_ticks as f64 / results.len() as f64;
+
+    (results, stats)
+}
+
+/// React `gm` until a tick changes it by less than `epsilon`, for at most `max_ticks` ticks.
+/// Returns the last mixture reached, the ticks taken and whether it settled.
+fn react_until_settled(gm: GasMixture, epsilon: f64, max_ticks: usize) -> (GasMixture, usize, bool) {
+    if gm.is_stable() {
+        return (gm, 0, true);
+    }
+
+    let mut cur = gm;
+    for tick in 1..=max_ticks {
+        let next = react_once(cur);
+        if next.approx_equals(&cur, epsilon) {
+            return (next, tick, true);
+        }
+        cur = next;
+    }
+
+    (cur, max_ticks, false)
+}
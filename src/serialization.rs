@@ -0,0 +1,111 @@
+//! Versioned serde support for mixtures, behind the `serde` feature.
+//!
+//! A mixture serializes as `{"version": 2, "gases": {"BZ": 0.0, "CO2": 0.0, ...}, "temperature": 293.15, "volume": 2500.0}`,
+//! every gas listed by its short name. `version` is `GasMixture::SERIAL_VERSION` on output. Inputs without one are read
+//! as version 1, the unversioned format, and inputs from a newer version are rejected since they may name gases this
+//! build does not know. An input must list every gas its version knew about; gases added after it are read as zero.
+
+use crate::gas::ALL_GASES;
+use crate::gas_mixture::Summary;
+use crate::{gen_gas_vec, Gas, GasMixture};
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// First serialized version that lists `gas`.
+fn introduced_in(gas: Gas) -> u32 {
+    match gas {
+        Gas::PlOx => 2,
+        _ => 1,
+    }
+}
+
+fn unversioned() -> u32 {
+    1
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedMixture {
+    #[serde(default = "unversioned")]
+    version: u32,
+    gases: BTreeMap<String, f64>,
+    temperature: f64,
+    volume: f64,
+}
+
+impl Serialize for GasMixture {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedMixture {
+            version: GasMixture::SERIAL_VERSION,
+            gases: self
+                .gases
+                .0
+                .iter()
+                .map(|(gas, amount)| (gas.short_name().to_string(), *amount))
+                .collect(),
+            temperature: self.temperature(),
+            volume: self.volume,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GasMixture {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedMixture::deserialize(deserializer)?;
+        let version = serialized.version;
+        if version == 0 || version > GasMixture::SERIAL_VERSION {
+            return Err(de::Error::custom(format!(
+                "unsupported version {}, expected 1 to {}",
+                version,
+                GasMixture::SERIAL_VERSION
+            )));
+        }
+
+        let mut gases = gen_gas_vec!();
+        let mut listed = Vec::with_capacity(serialized.gases.len());
+        for (name, amount) in serialized.gases {
+            let gas = Gas::from_name(&name).map_err(de::Error::custom)?;
+            if introduced_in(gas) > version {
+                return Err(de::Error::custom(format!("{:?} is not part of version {}", gas, version)));
+            }
+            if listed.contains(&gas) {
+                return Err(de::Error::custom(format!("{:?} is listed more than once", gas)));
+            }
+            listed.push(gas);
+            gases[gas] = amount;
+        }
+
+        if let Some(gas) = ALL_GASES
+            .iter()
+            .find(|gas| introduced_in(**gas) <= version && !listed.contains(gas))
+        {
+            return Err(de::Error::custom(format!("missing {:?}", gas)));
+        }
+
+        Ok(GasMixture::new(gases, serialized.temperature, serialized.volume))
+    }
+}
+
+impl Serialize for Summary {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct SerializedSummary<'a> {
+            gases: BTreeMap<&'a str, f64>,
+            temperature: f64,
+            pressure: f64,
+            volume: f64,
+            total_moles: f64,
+        }
+
+        SerializedSummary {
+            gases: self.gases.iter().map(|(gas, amount)| (gas.short_name(), *amount)).collect(),
+            temperature: self.temperature,
+            pressure: self.pressure,
+            volume: self.volume,
+            total_moles: self.total_moles,
+        }
+        .serialize(serializer)
+    }
+}
@@ -0,0 +1,43 @@
+//! Helpers for testing code built on the simulator, behind the `test-util` feature.
+//!
+//! Downstream crates enable it from their dev-dependencies with `features = ["test-util"]`. The examples here only
+//! run as doctests with the feature on, as in `cargo test --features test-util`.
+
+use crate::gas::ALL_GASES;
+use crate::GasMixture;
+
+/// Assert that `before` and `after` hold the same total amount of every gas and the same total thermal energy, within `tol`.
+///
+/// ```
+/// use tg_atmos_sim::test_util::assert_conserves_moles_and_energy;
+/// use tg_atmos_sim::{gen_gas_mix_with_temp, Gas, GasMixture};
+///
+/// let mut source = gen_gas_mix_with_temp!(with(Gas::N2 => 80.0, Gas::O2 => 20.0,) at(300.0) in(2500.0));
+/// let original = source;
+/// let oxygen = source.split_off_gas(Gas::O2);
+///
+/// assert_conserves_moles_and_energy(&[original], &[source, oxygen], 1e-9);
+/// ```
+pub fn assert_conserves_moles_and_energy(before: &[GasMixture], after: &[GasMixture], tol: f64) {
+    let total_of = |gms: &[GasMixture], f: &dyn Fn(&GasMixture) -> f64| gms.iter().map(f).sum::<f64>();
+
+    let discrepancies: Vec<String> = ALL_GASES
+        .iter()
+        .map(|&gas| (gas, total_of(before, &|gm| gm[gas]), total_of(after, &|gm| gm[gas])))
+        .filter(|(_, lhs, rhs)| (lhs - rhs).abs() > tol)
+        .map(|(gas, lhs, rhs)| format!("{:?}: {} -> {} ({:+})", gas, lhs, rhs, rhs - lhs))
+        .collect();
+
+    let energy_before = total_of(before, &GasMixture::get_energy);
+    let energy_after = total_of(after, &GasMixture::get_energy);
+    let energy_conserved = (energy_before - energy_after).abs() <= tol;
+
+    assert!(
+        discrepancies.is_empty() && energy_conserved,
+        "Moles or energy not conserved\n  moles: [{}]\n  energy: {} -> {} ({:+})",
+        discrepancies.join(", "),
+        energy_before,
+        energy_after,
+        energy_after - energy_before
+    );
+}
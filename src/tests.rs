@@ -1,83 +1,3030 @@
 #[cfg(test)]
 mod tests {
+    use crate::analysis;
+    use crate::command::apply_command;
+    use crate::constants as C;
+    use crate::gas::{GasEnumMap, ParseError, ALL_GASES, GAS_COUNT};
+    use crate::gas_mixture::{distill, joules_per_tick_to_watts, mix_temperatures, mix_to_ratio, temperature_sweep, AlarmThresholds, ArithmeticError, Hazard, ScrubAction, SleepLevel};
+    use crate::gas_source::{pump_moles, release_canister, transfer_until_pressure};
+    use crate::history::History;
     use crate::reactions as R;
-    use crate::{gen_gas_mix_with_temp, temperature, test_reaction, Gas, GasMixture};
+    use crate::test_util::assert_conserves_moles_and_energy;
+    use crate::{gen_gas_mix_with_temp, gen_gas_vec, temperature, test_reaction, Gas, GasMixture, GasMixtureSoA, GasSource, GasVec, Grid};
+    use crate::{gen_gas_mix_with_energy, reaction};
     use float_cmp::approx_eq;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn count_allocations(f: impl FnOnce()) -> usize {
+        let before = ALLOCATIONS.with(Cell::get);
+        f();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    #[test]
+    fn energy_merge_test_positive() {
+        let mix0 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 200.0,
+            )
+            at(temperature!(1000.0, K))
+        );
+
+        let mix1 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 200.0,
+            )
+            at(temperature!(2000.0, K))
+        );
+
+        assert!(
+            approx_eq!(
+                f64,
+                (mix0 + mix1).get_energy(),
+                mix0.get_energy() + mix1.get_energy()
+            ),
+            "Energy is not conserved with positive mixes"
+        );
+
+        assert!(
+            approx_eq!(f64, (mix0 + mix1)[Gas::Pl], 400.0),
+            "Matter is not conserved with positive mixes"
+        );
+    }
+
+    #[test]
+    fn energy_merge_test_negative() {
+        let mix0 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 200.0,
+            )
+            at(temperature!(1000.0, K))
+        );
+
+        let mix1 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => -50.0,
+            )
+            at(temperature!(2000.0, K))
+        );
+
+        assert!(
+            approx_eq!(
+                f64,
+                (mix0 + mix1).get_energy(),
+                mix0.get_energy() + mix1.get_energy()
+            ),
+            "Energy is not conserved with negative mixes"
+        );
+
+        assert!(
+            approx_eq!(f64, (mix0 + mix1)[Gas::Pl], 150.0),
+            "Matter is not conserved with negative mixes"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn energy_test_null_mix() {
+        gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => -50.0,
+                Gas::O2 => 50.0,
+            )
+            at(temperature!(2000.0, K))
+        );
+    }
+
+    #[test]
+    fn pressure_after_temperature_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 20.0,
+                Gas::N2 => 80.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        let heated = GasMixture {
+            temperature: temperature!(500.0, K),
+            ..mix
+        };
+
+        assert!(
+            approx_eq!(
+                f64,
+                mix.pressure_after_temperature(temperature!(500.0, K)),
+                heated.get_pressure()
+            ),
+            "Predicted pressure does not match the heated mixture"
+        );
+    }
+
+    #[test]
+    fn reaction_set_nonconvergence_test() {
+        let air = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0,
+                Gas::O2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        assert_eq!(R::ReactionSet::standard().detect_nonconvergence(&air, 100), None);
+
+        let oscillating = R::ReactionSet::default()
+            .with("swap_n2_o2", |gm| GasMixture {
+                gases: gen_gas_vec!(
+                    Gas::N2 => gm[Gas::O2],
+                    Gas::O2 => gm[Gas::N2],
+                    Gas::CO2 => gm[Gas::CO2],
+                ),
+                ..gm
+            })
+            .with("swap_o2_co2", |gm| GasMixture {
+                gases: gen_gas_vec!(
+                    Gas::N2 => gm[Gas::N2],
+                    Gas::O2 => gm[Gas::CO2],
+                    Gas::CO2 => gm[Gas::O2],
+                ),
+                ..gm
+            });
+
+        assert_eq!(oscillating.detect_nonconvergence(&air, 100), Some(3));
+    }
+
+    #[test]
+    fn atmos_mod_test() {
+        assert!(approx_eq!(f64, R::atmos_mod(7.0, 3.0), 1.0));
+        assert!(approx_eq!(f64, R::atmos_mod(-1.0, 3.0), 2.0));
+        assert!(approx_eq!(f64, R::atmos_mod(1.0, -3.0), -2.0));
+        assert!(approx_eq!(f64, R::atmos_mod(-7.5, -2.0), -1.5));
+        assert!(approx_eq!(f64, R::atmos_mod(0.0, 3.0), 0.0));
+    }
+
+    #[test]
+    fn atmos_mod_edge_case_test() {
+        assert_eq!(R::atmos_mod(5.0, 0.0), 5.0, "Zero modulus must return lhs");
+        assert_eq!(R::atmos_mod(-5.0, -0.0), -5.0, "Zero modulus must return lhs");
+
+        for &lhs in &[1e20, 1e300, -1e300] {
+            let result = R::atmos_mod(lhs, 5.96);
+            assert!(
+                result.is_finite() && (0.0..5.96).contains(&result),
+                "Large lhs {} produced {}",
+                lhs,
+                result
+            );
+        }
+
+        for &rhs in &[1e-300, 1e-310, 5e-324] {
+            let result = R::atmos_mod(1e10, rhs);
+            assert!(result.is_finite(), "Tiny rhs {} produced {}", rhs, result);
+        }
+
+        let smallest_toroid = C::TOROID_CALCULATED_THRESHOLD
+            + (C::FUSION_TEMPERATURE_THRESHOLD.log10() - C::FUSION_BASE_TEMPSCALE)
+                / C::FUSION_BUFFER_DIVISOR;
+        assert!(smallest_toroid > 0.0, "Fusion can reach a zero toroidal size");
+    }
+
+    #[test]
+    fn molar_mass_test() {
+        let oxygen = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 50.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        assert!(approx_eq!(f64, oxygen.average_molar_mass(), 32.0, epsilon = 0.01));
+        assert!(approx_eq!(f64, oxygen.total_mass(), 50.0 * Gas::O2.molar_mass()));
+        assert_eq!(GasMixture::zero().average_molar_mass(), 0.0);
+    }
+
+    #[test]
+    fn n2o_sleep_level_test() {
+        // R * T / V == 1, so moles of N2O equal its partial pressure
+        let with_n2o = |amount: f64| {
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::N2O => amount,
+                    Gas::O2 => 20.0,
+                )
+                at(temperature!(100.0, K))
+                in(831.0)
+            )
+        };
+
+        assert_eq!(with_n2o(0.0).n2o_sleep_level(), SleepLevel::None);
+        assert_eq!(with_n2o(0.99).n2o_sleep_level(), SleepLevel::None);
+        assert_eq!(with_n2o(1.0).n2o_sleep_level(), SleepLevel::Drowsy);
+        assert_eq!(with_n2o(4.99).n2o_sleep_level(), SleepLevel::Drowsy);
+        assert_eq!(with_n2o(5.0).n2o_sleep_level(), SleepLevel::Sleeping);
+    }
+
+    #[test]
+    fn checked_add_test() {
+        let mix0 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 200.0,
+            )
+            at(temperature!(1000.0, K))
+        );
+        let mix1 = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 200.0,
+            )
+            at(temperature!(2000.0, K))
+        );
+        assert_eq!(mix0.checked_add(mix1), Ok(mix0 + mix1));
+
+        let huge = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => f64::MAX,
+            )
+            at(temperature!(20.0, C))
+        );
+        assert_eq!(
+            huge.checked_add(huge),
+            Err(ArithmeticError::NonFiniteAmount(Gas::O2))
+        );
+    }
+
+    #[test]
+    fn react_once_energy_capped_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+                Gas::H2 => 50.0,
+            )
+            at(temperature!(1000.0, K))
+            in(2500.0)
+        );
+        let cap = 1e5;
+
+        let uncapped = R::react_once(mix);
+        assert!(uncapped.get_energy() - mix.get_energy() > cap);
+
+        let capped = R::react_once_energy_capped(mix, cap);
+        assert!(
+            capped.get_energy() - mix.get_energy() <= cap * (1.0 + 1e-9),
+            "Released {} with a cap of {}",
+            capped.get_energy() - mix.get_energy(),
+            cap
+        );
+
+        assert_eq!(R::react_once_energy_capped(mix, f64::INFINITY), uncapped);
+    }
+
+    #[test]
+    fn gas_from_name_test() {
+        for &gas in ALL_GASES.iter() {
+            assert_eq!(Gas::from_name(gas.short_name()), Ok(gas));
+            assert_eq!(Gas::from_name(gas.canonical_name()), Ok(gas));
+            assert_eq!(Gas::from_name(&gas.canonical_name().to_uppercase()), Ok(gas));
+        }
+
+        assert_eq!(Gas::from_name("o2"), Ok(Gas::O2));
+        assert_eq!(Gas::from_name("Carbon_Dioxide"), Ok(Gas::CO2));
+        assert_eq!(
+            Gas::from_name("phlogiston"),
+            Err(ParseError {
+                token: "phlogiston".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn detect_runaway_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+            )
+            at(temperature!(500.0, K))
+            in(2500.0)
+        );
+        let threshold = temperature!(1000.0, K);
+
+        let tick = R::detect_runaway(mix, 100, threshold).expect("Plasma fire did not run away");
+        let timeline = R::react_several(mix, 100);
+        assert!(timeline[tick].temperature > threshold);
+        assert!(timeline[..tick].iter().all(|gm| gm.temperature <= threshold));
+
+        assert_eq!(R::detect_runaway(mix, 100, f64::INFINITY), None);
+    }
+
+    #[test]
+    fn fingerprint_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0,
+                Gas::O2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+        let nearly_mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0 + 1e-9,
+                Gas::O2 => 20.0 - 1e-9,
+            )
+            at(temperature!(20.0, C) + 1e-9)
+            in(2500.0)
+        );
+        let other_mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 20.0,
+                Gas::O2 => 80.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        assert_eq!(mix.fingerprint(), mix.fingerprint());
+        assert_eq!(mix.fingerprint(), nearly_mix.fingerprint());
+        assert_ne!(mix.fingerprint(), other_mix.fingerprint());
+        assert_ne!(
+            mix.fingerprint(),
+            GasMixture {
+                temperature: temperature!(21.0, C),
+                ..mix
+            }
+            .fingerprint()
+        );
+    }
+
+    #[test]
+    fn react_once_simultaneous_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::H2 => 100.0,
+                Gas::O2 => 5.0,
+            )
+            at(temperature!(1000.0, K))
+            in(2500.0)
+        );
+
+        let sequential = R::react_once(mix);
+        let simultaneous = R::react_once_simultaneous(mix);
+
+        assert_ne!(sequential, simultaneous);
+        assert!(simultaneous[Gas::O2] >= 0.0, "O2 went negative: {}", simultaneous[Gas::O2]);
+        assert!(simultaneous[Gas::O2] < mix[Gas::O2]);
+        assert!(simultaneous[Gas::H2O] > 0.0 && simultaneous[Gas::CO2] > 0.0);
+
+        assert_eq!(R::react_once_simultaneous(GasMixture::zero()), GasMixture::zero());
+        let bz_only = gen_gas_mix_with_temp!(with(Gas::BZ => 10.0,) at(temperature!(1000.0, K)) in(2500.0));
+        assert_eq!(R::react_once_simultaneous(bz_only), bz_only);
+    }
+
+    #[test]
+    fn active_reaction_temperature_range_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2O => 20.0,
+                Gas::Pl => 20.0,
+                Gas::H2 => 10.0,
+                Gas::N2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        assert_eq!(
+            mix.active_reaction_temperature_range(),
+            Some((f64::NEG_INFINITY, R::hnob_synth::MIN_TEMPERATURE))
+        );
+
+        let inert = gen_gas_mix_with_temp!(
+            with(
+                Gas::CO2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+        assert_eq!(inert.active_reaction_temperature_range(), None);
+    }
+
+    #[test]
+    fn sample_escaping_gas_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 70.0,
+                Gas::O2 => 20.0,
+                Gas::Pl => 10.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut rng = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+
+        let samples = 100_000;
+        let mut counts = [0usize; 3];
+        for _ in 0..samples {
+            match mix.sample_escaping_gas(&mut rng) {
+                Some(Gas::N2) => counts[0] += 1,
+                Some(Gas::O2) => counts[1] += 1,
+                Some(Gas::Pl) => counts[2] += 1,
+                other => panic!("Sampled absent gas {:?}", other),
+            }
+        }
+
+        for (count, expected) in counts.iter().zip(&[0.7, 0.2, 0.1]) {
+            let fraction = *count as f64 / samples as f64;
+            assert!(
+                (fraction - expected).abs() < 0.01,
+                "Sampled fraction {} too far from {}",
+                fraction,
+                expected
+            );
+        }
+
+        assert_eq!(GasMixture::zero().sample_escaping_gas(&mut rng), None);
+    }
+
+    #[test]
+    fn label_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 21.0,
+                Gas::Pl => 72.0,
+                Gas::CO2 => 5.0,
+                Gas::N2 => 1.5,
+                Gas::H2 => 0.5,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        let dominant = mix.dominant_gases(usize::MAX);
+        assert_eq!(
+            dominant.iter().map(|(gas, _)| *gas).collect::<Vec<_>>(),
+            vec![Gas::Pl, Gas::O2, Gas::CO2, Gas::N2, Gas::H2]
+        );
+        assert!(approx_eq!(
+            f64,
+            dominant.iter().map(|(_, fraction)| fraction).sum::<f64>(),
+            1.0
+        ));
+
+        assert_eq!(mix.label(), "Plasma (72%), Oxygen (21%), Carbon dioxide (5%), trace");
+        assert_eq!(GasMixture::zero().label(), "empty");
+    }
+
+    #[test]
+    fn react_fractional_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+            )
+            at(temperature!(500.0, K))
+            in(2500.0)
+        );
+
+        assert_eq!(R::react_fractional(mix, 0.0), mix);
+        assert_eq!(R::react_fractional(mix, 3.0), *R::react_several(mix, 3).last().unwrap());
+
+        let timeline = R::react_several(mix, 4);
+        let halfway = R::react_fractional(mix, 3.5);
+        assert!(approx_eq!(
+            f64,
+            halfway[Gas::Pl],
+            (timeline[2][Gas::Pl] + timeline[3][Gas::Pl]) / 2.0
+        ));
+        assert!(halfway.temperature > timeline[2].temperature && halfway.temperature < timeline[3].temperature);
+
+        assert_eq!(R::react_fractional(GasMixture::zero(), 0.5), GasMixture::zero());
+        let bz_only = gen_gas_mix_with_temp!(with(Gas::BZ => 10.0,) at(temperature!(500.0, K)) in(2500.0));
+        assert_eq!(R::react_fractional(bz_only, 2.5), bz_only);
+        let empty_step = R::scale_reaction_step(bz_only, bz_only.with_gas(Gas::BZ, 10.0), 0.5);
+        assert_eq!(empty_step[Gas::BZ], 15.0);
+        assert_eq!(empty_step.temperature(), bz_only.temperature());
+    }
+
+    #[test]
+    fn split_off_gas_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0,
+                Gas::O2 => 20.0,
+                Gas::CO2 => 5.0,
+            )
+            at(temperature!(400.0, K))
+            in(2500.0)
+        );
+
+        let mut source = mix;
+        let oxygen = source.split_off_gas(Gas::O2);
+
+        assert_eq!(source[Gas::O2], 0.0);
+        assert_eq!(oxygen[Gas::O2], 20.0);
+        assert_eq!(oxygen.get_total_amount(), 20.0);
+        assert!(approx_eq!(f64, source.temperature, mix.temperature));
+        assert!(approx_eq!(f64, oxygen.temperature, mix.temperature));
+        assert!(approx_eq!(
+            f64,
+            source.get_total_amount() + oxygen.get_total_amount(),
+            mix.get_total_amount()
+        ));
+        assert!(approx_eq!(
+            f64,
+            source.get_energy() + oxygen.get_energy(),
+            mix.get_energy()
+        ));
+    }
+
+    #[test]
+    fn explosion_potential_test() {
+        let bomb = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 50.0,
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(20.0, C))
+            in(140.0)
+        );
+        assert!(approx_eq!(
+            f64,
+            bomb.explosion_potential(),
+            50.0 * C::FIRE_PLASMA_ENERGY_RELEASED
+        ));
+
+        let oxygen_starved = GasMixture {
+            gases: gen_gas_vec!(
+                Gas::Pl => 50.0,
+                Gas::O2 => 4.0,
+            ),
+            ..bomb
+        };
+        assert!(approx_eq!(
+            f64,
+            oxygen_starved.explosion_potential(),
+            10.0 * C::FIRE_PLASMA_ENERGY_RELEASED
+        ));
+
+        let inert = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 100.0,
+                Gas::CO2 => 50.0,
+            )
+            at(temperature!(20.0, C))
+            in(140.0)
+        );
+        assert_eq!(inert.explosion_potential(), 0.0);
+    }
+
+    #[test]
+    fn reaction_specs_test() {
+        let specs = R::reaction_specs();
+        assert_eq!(
+            specs.iter().map(|spec| spec.name).collect::<Vec<_>>(),
+            R::STANDARD_REACTIONS.iter().map(|(name, _)| *name).collect::<Vec<_>>()
+        );
+        let fire = GasMixture::standard_air().with_gas(Gas::Pl, 50.0).with_gas(Gas::N2O, 20.0).clone_at_temperature(1000.0);
+        assert_eq!(
+            R::react_once_ignore_noblium(fire),
+            R::STANDARD_REACTIONS.iter().fold(fire, |cur, (_, reaction)| reaction(cur))
+        );
+
+        assert_eq!(
+            R::plasma_fire::SPEC.requirements,
+            &[(Gas::Pl, C::MINIMUM_MOLE_COUNT), (Gas::O2, C::MINIMUM_MOLE_COUNT)]
+        );
+        assert_eq!(R::plasma_fire::SPEC.min_temperature, C::PLASMA_MINIMUM_BURN_TEMPERATURE);
+        assert_eq!(R::bz_synth::SPEC.min_temperature, f64::NEG_INFINITY);
+
+        // Falling short of any single requirement must keep the reaction from firing
+        for (spec, (_, reaction)) in specs.iter().zip(R::STANDARD_REACTIONS) {
+            for (short_gas, _) in spec.requirements {
+                let gm = GasMixture {
+                    gases: GasVec(GasEnumMap::from(|gas| {
+                        spec.requirements
+                            .iter()
+                            .find(|(required, _)| *required == gas)
+                            .map_or(0.0, |(_, amount)| if gas == *short_gas { amount * 0.99 } else { *amount })
+                    })),
+                    temperature: spec.min_temperature.max(temperature!(20.0, C)) * 1.01,
+                    volume: 2500.0,
+                };
+                assert_eq!(reaction(gm), gm, "{} fired without enough {:?}", spec.name, short_gas);
+            }
+        }
+    }
+
+    #[test]
+    fn expose_to_space_test() {
+        let mut tile = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0,
+                Gas::O2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        for _ in 0..100 {
+            let before = tile;
+            let lost = tile.expose_to_space(0.2);
+
+            assert!(approx_eq!(
+                f64,
+                tile.get_total_amount() + lost.get_total_amount(),
+                before.get_total_amount()
+            ));
+            assert!(tile.temperature <= before.temperature);
+        }
+
+        assert!(tile.get_total_amount() < 1e-6);
+        assert!(approx_eq!(f64, tile.temperature, C::TCMB, epsilon = 1e-6));
+
+        // Cooling goes through set_temperature, so a tile built below TCMB is floored rather than left below it
+        let mut frozen = GasMixture::from_parts(gen_gas_vec!(Gas::N2 => 10.0,), 1.0, 2500.0);
+        frozen.expose_to_space(0.5);
+        assert_eq!(frozen.temperature, C::TCMB);
+    }
+
+    #[test]
+    fn react_once_in_place_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 522.0,
+                Gas::O2 => 970.0,
+                Gas::CO2 => 358.0,
+                Gas::N2O => 298.0,
+                Gas::Pl => 582.0,
+                Gas::H2 => 134.0,
+                Gas::BZ => 74.0,
+            )
+            at(temperature!(6000194.0, K))
+            in(2500.0)
+        );
+
+        let mut in_place = mix;
+        let allocations = count_allocations(|| {
+            for _ in 0..10 {
+                in_place.react_once_in_place();
+            }
+        });
+
+        assert_eq!(allocations, 0, "Reacting a single mixture allocated");
+        assert_eq!(in_place, *R::react_several(mix, 10).last().unwrap());
+    }
+
+    #[test]
+    fn with_gas_test() {
+        let empty = GasMixture {
+            temperature: temperature!(20.0, C),
+            volume: 2500.0,
+            ..GasMixture::zero()
+        };
+
+        let air = empty
+            .with_gas(Gas::N2, 80.0)
+            .with_gas(Gas::O2, 15.0)
+            .with_gas(Gas::O2, 5.0);
+
+        assert_eq!(empty.get_total_amount(), 0.0);
+        assert_eq!(air[Gas::N2], 80.0);
+        assert_eq!(air[Gas::O2], 20.0);
+        assert_eq!(air.temperature, empty.temperature);
+        assert_eq!(air.volume, empty.volume);
+    }
+
+    #[test]
+    fn react_once_rate_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+                Gas::H2 => 50.0,
+            )
+            at(temperature!(1000.0, K))
+            in(2500.0)
+        );
+
+        assert_eq!(R::react_once_rate(mix, 1.0), R::react_once(mix));
+        assert_eq!(R::react_once_rate(mix, 0.0), mix);
+
+        let slow = R::react_once_rate(mix, 0.5);
+        let normal = R::react_once(mix);
+        assert!(slow[Gas::Pl] > normal[Gas::Pl] && slow[Gas::Pl] < mix[Gas::Pl]);
+
+        let fast = R::react_once_rate(mix, 1000.0);
+        assert!(fast.gases.0.values().all(|amount| *amount >= 0.0));
+        assert!(fast[Gas::Pl] < normal[Gas::Pl]);
+    }
+
+    #[test]
+    fn assert_conserves_test() {
+        let mut source = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 80.0,
+                Gas::O2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+        let original = source;
+        let oxygen = source.split_off_gas(Gas::O2);
+
+        assert_conserves_moles_and_energy(&[original], &[source, oxygen], 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "O2: 20 -> 10")]
+    fn assert_conserves_failure_test() {
+        let before = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 20.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+        let after = GasMixture {
+            gases: before.gases * 0.5,
+            ..before
+        };
+
+        assert_conserves_moles_and_energy(&[before], &[after], 1e-9);
+    }
+
+    #[test]
+    fn temperature_floor_test() {
+        let frozen = GasMixture::new(gen_gas_vec!(Gas::O2 => 20.0,), 0.0, 2500.0);
+        assert_eq!(frozen.temperature, C::TCMB);
+        assert!(frozen.get_pressure().is_finite() && frozen.get_pressure() > 0.0);
+        assert!(approx_eq!(f64, frozen.get_energy(), 20.0 * 20.0 * C::TCMB));
+
+        let mut mix = frozen;
+        mix.set_temperature(-50.0);
+        assert_eq!(mix.temperature, C::TCMB);
+        assert_eq!((mix + -1e9).temperature, C::TCMB);
+
+        for (name, reaction) in R::STANDARD_REACTIONS {
+            let result = reaction(frozen.with_gas(Gas::N2O, 20.0).with_gas(Gas::Pl, 20.0));
+            assert!(result.temperature >= C::TCMB, "{} went below TCMB", name);
+        }
+    }
+
+    #[test]
+    fn distill_test() {
+        let air = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 78.0,
+                Gas::O2 => 21.0,
+                Gas::CO2 => 1.0,
+            )
+            at(temperature!(20.0, C))
+            in(2500.0)
+        );
+
+        let (oxygen, remainder) = distill(air, Gas::O2);
+
+        assert_eq!(oxygen[Gas::O2], 21.0);
+        assert_eq!(oxygen.get_total_amount(), 21.0);
+        assert_eq!(remainder[Gas::O2], 0.0);
+        assert_eq!(remainder[Gas::N2], 78.0);
+        assert_conserves_moles_and_energy(&[air], &[oxygen, remainder], 1e-9);
+    }
+
+    #[test]
+    fn is_stable_test() {
+        let air = GasMixture::standard_air();
+        assert!(approx_eq!(f64, air.get_pressure(), C::ONE_ATMOSPHERE, epsilon = 1e-9));
+        assert!(air.is_stable());
+        assert_eq!(R::react_once(air), air);
+
+        let fire = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+            )
+            at(temperature!(500.0, K))
+            in(2500.0)
+        );
+        assert!(!fire.is_stable());
+        assert_ne!(R::react_once(fire), fire);
+
+        let suppressed = fire.with_gas(Gas::HNb, 10.0);
+        assert!(suppressed.is_stable());
+    }
+
+    #[test]
+    fn prune_traces_test() {
+        let mut mix = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 1e-25)
+            .with_gas(Gas::N2O, -1e-25);
+        let energy = mix.get_energy();
+
+        mix.prune_traces(C::MINIMUM_MOLE_COUNT);
+
+        assert_eq!(mix[Gas::Pl], 0.0);
+        assert_eq!(mix[Gas::N2O], 0.0);
+        assert_eq!(mix[Gas::O2], GasMixture::standard_air()[Gas::O2]);
+        assert!(approx_eq!(f64, mix.get_energy(), energy));
+        assert!(mix.is_stable());
+    }
+
+    #[test]
+    fn hazard_score_test() {
+        let air = GasMixture::standard_air();
+        let hot_air = GasMixture {
+            temperature: temperature!(80.0, C),
+            ..air
+        };
+        let fire = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+                Gas::CO2 => 10.0,
+            )
+            at(temperature!(1500.0, K))
+            in(2500.0)
+        );
+
+        assert!(approx_eq!(f64, air.hazard_score(), 0.0, epsilon = 1e-9));
+
+        let mut tiles = vec![air, fire, hot_air];
+        tiles.sort_by(|lhs, rhs| rhs.cmp_by_hazard_score(lhs));
+        assert_eq!(tiles, vec![fire, hot_air, air]);
+
+        tiles.sort_by(GasMixture::cmp_by_temperature);
+        assert_eq!(tiles, vec![air, hot_air, fire]);
+        assert_eq!(air.cmp_by_pressure(&hot_air), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn mutable_api_parity_test() {
+        let air = GasMixture::standard_air();
+        let fire = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+            )
+            at(temperature!(500.0, K))
+            in(2500.0)
+        );
+
+        let mut merged = air;
+        merged.merge_mut(&fire);
+        assert_eq!(merged, air + fire);
+
+        let mut merged = air;
+        merged += fire;
+        assert_eq!(merged, air + fire);
+
+        let mut heated = air;
+        heated.adjust_thermal_energy_mut(1e5);
+        assert_eq!(heated, air.adjust_thermal_energy(1e5));
+
+        let mut heated = air;
+        heated += 1e5;
+        assert_eq!(heated, air + 1e5);
+
+        let mut reacted = fire;
+        reacted.react_once_in_place();
+        assert_eq!(reacted, R::react_once(fire));
+    }
+
+    #[test]
+    #[ignore]
+    fn mutable_grid_benchmark() {
+        use std::time::Instant;
+
+        let grid = soa_sample_mixtures(1_000_000);
+
+        let start = Instant::now();
+        let by_value: Vec<GasMixture> = grid.iter().map(|gm| R::react_once(*gm)).collect();
+        let by_value_time = start.elapsed();
+
+        let mut in_place = grid;
+        let start = Instant::now();
+        in_place.iter_mut().for_each(GasMixture::react_once_in_place);
+        let in_place_time = start.elapsed();
+
+        assert_eq!(by_value, in_place);
+        println!("by value: {:?}, in place: {:?}", by_value_time, in_place_time);
+    }
+
+    #[test]
+    fn energy_by_gas_test() {
+        let air = GasMixture::standard_air();
+        assert!(approx_eq!(
+            f64,
+            air.energy_by_gas().values().sum::<f64>(),
+            air.get_energy()
+        ));
+
+        let plasma = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 50.0,
+            )
+            at(temperature!(500.0, K))
+            in(2500.0)
+        );
+        assert_eq!(plasma.energy_contribution(Gas::Pl), plasma.get_energy());
+        assert_eq!(plasma.energy_contribution(Gas::O2), 0.0);
+    }
+
+    #[test]
+    fn reaction_threshold_test() {
+        let thresholds = [
+            (R::hnob_decomp::SPEC, 1e7),
+            (R::n2o_decomp::SPEC, 1400.0),
+            (R::trit_fire::SPEC, 373.15),
+            (R::plasma_fire::SPEC, 373.15),
+            (R::fusion::SPEC, 10000.0),
+            (R::nitryl_formation::SPEC, 22389.0),
+            (R::bz_synth::SPEC, f64::NEG_INFINITY),
+            (R::stimulum_synth::SPEC, 50000.0),
+            (R::stimulum_decay::SPEC, 1000.0),
+            (R::hnob_synth::SPEC, 5e6),
+        ];
+        assert_eq!(thresholds.len(), R::STANDARD_REACTION_SPECS.len());
+
+        for (spec, expected) in thresholds.iter() {
+            assert!(
+                approx_eq!(f64, spec.min_temperature, *expected, epsilon = 1e-9),
+                "{} gates at {} instead of {}",
+                spec.name,
+                spec.min_temperature,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn mix_to_ratio_test() {
+        let oxygen = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(20.0, C))
+            in(1000.0)
+        );
+        let plasma = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+            )
+            at(temperature!(20.0, C))
+            in(1000.0)
+        );
+
+        let blend = mix_to_ratio(&oxygen, &plasma, Gas::O2, Gas::Pl, 2.0).unwrap();
+        assert!(approx_eq!(
+            f64,
+            blend.partial_pressure(Gas::O2) / blend.partial_pressure(Gas::Pl),
+            2.0
+        ));
+        assert_eq!(blend[Gas::O2], 100.0);
+        assert_eq!(blend[Gas::Pl], 50.0);
+
+        assert_eq!(mix_to_ratio(&oxygen, &oxygen, Gas::O2, Gas::Pl, 2.0), None);
+    }
+
+    #[test]
+    fn react_until_done_stats_test() {
+        // 0.02 moles of stimulum lose 5% per tick and drop below MINIMUM_MOLE_COUNT after 14 ticks
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 1000.0,
+                Gas::ST => 0.02,
+            )
+            at(temperature!(2000.0, K))
+            in(2500.0)
+        );
+
+        let (result, counts) = R::react_until_done_stats(mix);
+        assert_eq!(result, R::react_until_done(mix));
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts["stimulum_decay"], 14);
+
+        let (_, counts) = R::react_until_done_stats(GasMixture::standard_air());
+        assert!(counts.is_empty());
+    }
+
+    #[test]
+    fn summary_serialize_test() {
+        let mut air = GasMixture::standard_air();
+        air.add_gas(Gas::Pl, C::MINIMUM_MOLE_COUNT / 2.);
+        let json = serde_json::to_value(air.summary()).unwrap();
+
+        assert_eq!(json["gases"].as_object().unwrap().keys().collect::<Vec<_>>(), vec!["N2", "O2"]);
+        assert_eq!(json["gases"]["O2"], air[Gas::O2]);
+        assert_eq!(json["temperature"], air.temperature());
+        assert_eq!(json["total_moles"], air.get_total_amount());
+    }
+
+    #[test]
+    fn moles_from_pressure_test() {
+        assert!(approx_eq!(
+            f64,
+            GasMixture::moles_from_pressure(C::ONE_ATMOSPHERE, C::T20C, C::CELL_VOLUME),
+            C::MOLES_CELLSTANDARD,
+            ulps = 2
+        ));
+
+        for gm in soa_sample_mixtures(50).iter() {
+            let moles = GasMixture::moles_from_pressure(gm.get_pressure(), gm.temperature, gm.volume);
+            assert!(approx_eq!(f64, moles, gm.get_total_amount(), ulps = 4));
+        }
+
+        for volume in [0.0, 1e-9, C::MINIMUM_VOLUME].iter() {
+            let tiny = GasMixture { volume: *volume, ..GasMixture::standard_air().portion(1e-6) };
+            let moles = GasMixture::moles_from_pressure(tiny.get_pressure(), tiny.temperature, tiny.volume);
+            assert!(approx_eq!(f64, moles, tiny.get_total_amount(), ulps = 4));
+            assert!(approx_eq!(
+                f64,
+                GasMixture::moles_from_pressure(tiny.partial_pressure(Gas::O2), tiny.temperature, tiny.volume),
+                tiny[Gas::O2],
+                ulps = 4
+            ));
+        }
+    }
+
+    #[test]
+    fn react_only_test() {
+        let all: Vec<&str> = R::STANDARD_REACTIONS.iter().map(|(name, _)| *name).collect();
+        for gm in soa_sample_mixtures(50).iter() {
+            assert_eq!(R::react_only(*gm, &all), R::react_once(*gm));
+        }
+
+        let fire = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 50.0)
+            .clone_at_temperature(1000.0);
+        assert_eq!(R::react_only(fire, &["fusion", "no_such_reaction"]), fire);
+        assert_eq!(R::react_only(fire, &["plasma_fire"]), R::plasma_fire(fire));
+        assert_eq!(R::react_only(fire, &[]), fire);
+    }
+
+    #[test]
+    fn hnob_decomp_test() {
+        let locked = gen_gas_mix_with_temp!(
+            with(
+                Gas::HNb => 50.0,
+                Gas::Pl => 100.0,
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(2e7, K))
+            in(C::CELL_VOLUME)
+        );
+        assert!(!locked.is_stable());
+
+        let reacted = R::react_once(locked);
+        assert!(reacted[Gas::HNb] < locked[Gas::HNb]);
+        assert_eq!(reacted[Gas::Pl], locked[Gas::Pl]);
+        assert!(reacted.temperature > locked.temperature);
+        let decomposed = locked[Gas::HNb] - reacted[Gas::HNb];
+        assert!(approx_eq!(f64, reacted[Gas::N2], 20. * decomposed, epsilon = 1e-9));
+        assert!(approx_eq!(
+            f64,
+            reacted.get_energy() - locked.get_energy(),
+            decomposed * C::NOBLIUM_FORMATION_ENERGY,
+            epsilon = 1e-3
+        ));
+        assert_eq!(R::ReactionSet::standard().react_once(locked), reacted);
+        assert_eq!(R::react_once_traced(locked).0, reacted);
+
+        let cold = locked.clone_at_temperature(1000.0);
+        assert!(cold.is_stable());
+        assert_eq!(R::react_once(cold), cold);
+
+        let unlocked = R::react_several(locked, 1000)
+            .into_iter()
+            .position(|gm| gm[Gas::HNb] < 5.0);
+        assert!(unlocked.is_some());
+    }
+
+    #[test]
+    fn gas_vec_arithmetic_test() {
+        let air = gen_gas_vec!(Gas::O2 => 21.0, Gas::N2 => 79.0,);
+        let fuel = gen_gas_vec!(Gas::Pl => 10.0, Gas::O2 => 5.0,);
+
+        let mut needed = air * 2. - fuel;
+        assert_eq!(needed[Gas::O2], 37.0);
+        assert_eq!(needed[Gas::N2], 158.0);
+        assert_eq!(needed[Gas::Pl], -10.0);
+        assert_eq!(needed.total(), 185.0);
+
+        needed[Gas::Pl] = 0.0;
+        assert_eq!((needed + fuel)[Gas::Pl], 10.0);
+        assert_eq!(air - air, gen_gas_vec!());
+    }
+
+    #[test]
+    fn temperature_sweep_test() {
+        let base = GasMixture::standard_air().with_gas(Gas::Pl, 10.0);
+        let sweep = temperature_sweep(&base, 300.0, 1200.0, 4);
+
+        let temperatures: Vec<f64> = sweep.iter().map(|gm| gm.temperature).collect();
+        assert_eq!(temperatures, vec![300.0, 600.0, 900.0, 1200.0]);
+        for gm in sweep.iter() {
+            assert_eq!(gm.gases, base.gases);
+            assert_eq!(gm.volume, base.volume);
+            assert!(approx_eq!(f64, gm.get_energy(), gm.get_heat_cap() * gm.temperature));
+        }
+
+        assert!(temperature_sweep(&base, 300.0, 1200.0, 0).is_empty());
+        assert_eq!(temperature_sweep(&base, 300.0, 1200.0, 1), vec![base.clone_at_temperature(300.0)]);
+        assert_eq!(base.clone_at_temperature(0.0).temperature, C::TCMB);
+    }
+
+    #[test]
+    fn reactions_possible_at_test() {
+        for temperature in [0.0, C::TCMB, C::T20C, 500.0, 1e4, 1e6, f64::INFINITY].iter() {
+            let mut indexed = R::reactions_possible_at(*temperature).to_vec();
+            let mut brute_force: Vec<&str> = R::STANDARD_REACTION_SPECS
+                .iter()
+                .filter(|spec| spec.min_temperature <= *temperature)
+                .map(|spec| spec.name)
+                .collect();
+            indexed.sort_unstable();
+            brute_force.sort_unstable();
+
+            assert_eq!(indexed, brute_force, "at {} K", temperature);
+        }
+        assert!(R::reactions_possible_at(f64::NAN).is_empty());
+        assert_eq!(R::reactions_possible_at(f64::INFINITY).len(), R::STANDARD_REACTIONS.len());
+    }
+
+    #[test]
+    fn reaction_delta_test() {
+        let gm = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::H2 => 100.0,
+                Gas::O2 => 2000.0,
+            )
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let (reacted, outcomes) = R::react_once_traced(gm);
+        assert!(outcomes.len() >= 2);
+
+        let forward = outcomes.iter().fold(gm, |cur, outcome| cur.apply_delta(outcome));
+        let back = outcomes.iter().rev().fold(forward, |cur, outcome| cur.revert_delta(outcome));
+
+        for (actual, expected) in [(forward, reacted), (back, gm)].iter() {
+            assert!(approx_eq!(f64, actual.temperature, expected.temperature, epsilon = 1e-6));
+            assert_eq!(actual.volume, expected.volume);
+            for gas in ALL_GASES.iter() {
+                assert!(approx_eq!(f64, actual[*gas], expected[*gas], epsilon = 1e-9));
+            }
+        }
+    }
+
+    #[test]
+    fn react_once_ignore_noblium_test() {
+        let gm = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
+                Gas::HNb => 10.0,
+            )
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        assert_eq!(R::react_once(gm), gm);
+
+        let unsuppressed = R::react_once_ignore_noblium(gm);
+        assert!(unsuppressed[Gas::Pl] < gm[Gas::Pl]);
+        assert!(unsuppressed.temperature > gm.temperature);
+
+        let without_noblium = gm.with_gas(Gas::HNb, -10.0);
+        assert_eq!(
+            R::react_once_ignore_noblium(without_noblium),
+            R::react_once(without_noblium)
+        );
+    }
+
+    #[test]
+    fn serial_version_test() {
+        let air = GasMixture::standard_air();
+        let json = serde_json::to_string(&air).unwrap();
+        assert!(json.starts_with(&format!(r#"{{"version":{},"#, GasMixture::SERIAL_VERSION)));
+        assert_eq!(serde_json::from_str::<GasMixture>(&json).unwrap(), air);
+
+        // Version 1 predates pluoxium, so it is missing from the fixture and must come back as zero
+        let v1_gases = r#""N2": 79.0, "O2": 21.0, "CO2": 0, "N2O": 0, "Pl": 0, "H2O": 0, "HNb": 0, "NO2": 0, "H2": 0, "BZ": 0, "ST": 0"#;
+        let v1 = format!(r#"{{"gases": {{{}}}, "temperature": 293.15, "volume": 2500}}"#, v1_gases);
+        let migrated: GasMixture = serde_json::from_str(&v1).unwrap();
+        assert_eq!(migrated[Gas::PlOx], 0.0);
+        assert_eq!(migrated[Gas::N2], 79.0);
+        assert_eq!(migrated.temperature(), 293.15);
+        let explicit_v1 = format!(r#"{{"version": 1, "gases": {{{}}}, "temperature": 293.15, "volume": 2500}}"#, v1_gases);
+        assert_eq!(serde_json::from_str::<GasMixture>(&explicit_v1).unwrap(), migrated);
+
+        // The current version must list pluoxium, and version 1 must not
+        let v2_without_plox = format!(r#"{{"version": 2, "gases": {{{}}}, "temperature": 293.15, "volume": 2500}}"#, v1_gases);
+        assert!(serde_json::from_str::<GasMixture>(&v2_without_plox).is_err());
+        let v1_with_plox = format!(r#"{{"gases": {{{}, "PlOx": 1}}, "temperature": 293.15, "volume": 2500}}"#, v1_gases);
+        assert!(serde_json::from_str::<GasMixture>(&v1_with_plox).is_err());
+
+        let future = json.replacen(
+            &format!(r#""version":{}"#, GasMixture::SERIAL_VERSION),
+            &format!(r#""version":{}"#, GasMixture::SERIAL_VERSION + 1),
+            1,
+        );
+        assert!(serde_json::from_str::<GasMixture>(&future).is_err());
+        assert!(serde_json::from_str::<GasMixture>(&json.replace("\"PlOx\"", "\"phlogiston\"")).is_err());
+    }
+
+    #[test]
+    fn react_once_capped_product_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2O => 15.0,
+                Gas::Pl => 45.0,
+            )
+            at(temperature!(2.0, K))
+            in(2500.0)
+        );
+        assert!(R::react_once(mix)[Gas::BZ] > 5.0);
+
+        let capped = R::react_once_capped_product(mix, Gas::BZ, 5.0);
+        assert!(capped[Gas::BZ] <= 5.0 + 1e-9, "BZ exceeded the cap: {}", capped[Gas::BZ]);
+        assert!(capped[Gas::BZ] > 0.0);
+
+        let already_full = R::react_once_capped_product(mix.with_gas(Gas::BZ, 10.0), Gas::BZ, 5.0);
+        assert_eq!(already_full[Gas::BZ], 10.0);
+    }
+
+    reaction! (
+        called(pressurized_oxidation)
+        with_present(
+            Gas::O2
+        )
+        with_partial_pressure(
+            Gas::O2 => 100.0
+        )
+        at(f64::NEG_INFINITY)
+        with_gm_as(gm) => {
+            gm + gen_gas_mix_with_energy!(
+                with(
+                    Gas::O2 => -1.0,
+                    Gas::CO2 => 1.0,
+                )
+                at(0.0)
+            )
+        }
+    );
+
+    #[test]
+    fn partial_pressure_gate_test() {
+        let small = gen_gas_mix_with_temp!(
+            with(
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(20.0, C))
+            in(1000.0)
+        );
+        let large = GasMixture {
+            volume: 100000.0,
+            ..small
+        };
+        assert!(small.partial_pressure(Gas::O2) >= 100.0);
+        assert!(large.partial_pressure(Gas::O2) < 100.0);
+
+        assert_eq!(pressurized_oxidation(small)[Gas::CO2], 1.0);
+        assert_eq!(pressurized_oxidation(large), large);
+
+        assert!(pressurized_oxidation::SPEC.can_react(&small));
+        assert!(!pressurized_oxidation::SPEC.can_react(&large));
+        assert!(R::STANDARD_REACTION_SPECS
+            .iter()
+            .all(|spec| spec.partial_pressure_requirements.is_empty()));
+    }
+
+    #[test]
+    fn react_several_energy_by_reaction_test() {
+        let gm = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::H2 => 100.0,
+                Gas::O2 => 2000.0,
+            )
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let (ticks, energies) = R::react_several_energy_by_reaction(gm, 10);
+
+        assert_eq!(ticks, R::react_several(gm, 10));
+        assert!(energies["plasma_fire"] > 0.0);
+        assert!(energies["trit_fire"] > 0.0);
+        assert!(!energies.contains_key("fusion"));
+
+        let total_gain = ticks.last().unwrap().get_energy() - gm.get_energy();
+        assert!(approx_eq!(
+            f64,
+            energies.values().sum::<f64>(),
+            total_gain,
+            epsilon = total_gain * 1e-9
+        ));
+    }
+
+    /// Random mixtures across the whole input contract of `react_once`: each gas absent or anywhere from
+    /// a trace to a huge amount, temperatures from TCMB to far beyond fusion and a wide range of volumes.
+    fn fuzz_mixtures(count: usize, seed: u64) -> Vec<GasMixture> {
+        let mut state = seed;
+        let mut rng = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let mut log_uniform = |min: f64, max: f64| (min.ln() + rng() * (max.ln() - min.ln())).exp();
+
+        (0..count)
+            .map(|_| {
+                let mut gases = gen_gas_vec!();
+                for gas in ALL_GASES.iter() {
+                    if log_uniform(1.0, 4.0) < 2.0 {
+                        gases[*gas] = log_uniform(1e-10, 1e8);
+                    }
+                }
+                let temperature = log_uniform(C::TCMB, 1e10);
+                let volume = log_uniform(C::MINIMUM_VOLUME * 1e-9, 1e6);
+                GasMixture::new(gases, temperature, volume)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn react_once_fuzz_test() {
+        for gm in fuzz_mixtures(200_000, 0x9e37_79b9_7f4a_7c15).iter() {
+            let result = R::react_once(*gm);
+            let physical = result.temperature.is_finite()
+                && result.temperature >= C::TCMB
+                && result.gases.0.values().all(|amount| amount.is_finite() && *amount >= 0.0);
+            assert!(physical, "{:?} reacted into {:?}", gm, result);
+        }
+    }
+
+    #[test]
+    fn fog_density_test() {
+        let fog = |gas: Gas, moles: f64| gen_gas_mix_with_temp!(with(Gas::N2 => 100.0,) at(temperature!(C::T20C, K))).with_gas(gas, moles);
+
+        assert_eq!(fog(Gas::Pl, 0.0).fog_density(Gas::Pl), 0.0);
+        assert_eq!(fog(Gas::Pl, C::MOLES_GAS_VISIBLE / 2.).fog_density(Gas::Pl), 0.0);
+        assert_eq!(fog(Gas::Pl, C::MOLES_GAS_VISIBLE).fog_density(Gas::Pl), 0.0);
+        let halfway = (C::MOLES_GAS_VISIBLE + C::MOLES_PLASMA_OPAQUE) / 2.;
+        assert!(approx_eq!(f64, fog(Gas::Pl, halfway).fog_density(Gas::Pl), 0.5));
+        assert_eq!(fog(Gas::Pl, C::MOLES_PLASMA_OPAQUE).fog_density(Gas::Pl), 1.0);
+        assert_eq!(fog(Gas::Pl, 1000.0).fog_density(Gas::Pl), 1.0);
+
+        assert_eq!(fog(Gas::N2O, C::MOLES_GAS_VISIBLE).fog_density(Gas::N2O), 0.0);
+        assert_eq!(fog(Gas::N2O, C::MOLES_N2O_OPAQUE).fog_density(Gas::N2O), 1.0);
+        assert_eq!(fog(Gas::N2, 1000.0).fog_density(Gas::N2), 0.0);
+
+        let cloud = fog(Gas::Pl, 10.0).with_gas(Gas::H2O, 1.0).with_gas(Gas::N2O, C::MOLES_GAS_VISIBLE);
+        assert_eq!(cloud.visible_gases(), vec![Gas::Pl, Gas::H2O]);
+    }
+
+    #[test]
+    fn history_test() {
+        let original = gen_gas_mix_with_temp!(
+            with(Gas::Pl => 50.0, Gas::O2 => 100.0,)
+            at(temperature!(500.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let mut history = History::new(original, 16);
+        let mut states = vec![history.current()];
+        history.add(Gas::N2, 20.0);
+        states.push(history.current());
+        history.heat(1e6);
+        states.push(history.current());
+        for _ in 0..5 {
+            history.react();
+            states.push(history.current());
+        }
+        assert!(history.current()[Gas::CO2] > 0.0);
+
+        for state in states.iter().rev().skip(1) {
+            assert!(history.undo());
+            assert!(history.current().approx_equals(state, 1e-12), "{:?} != {:?}", history.current(), state);
+        }
+        assert!(!history.undo());
+        assert!(history.current().approx_equals(&original, 1e-12));
+
+        for state in states.iter().skip(1) {
+            assert!(history.redo());
+            assert!(history.current().approx_equals(state, 1e-12), "{:?} != {:?}", history.current(), state);
+        }
+        assert!(!history.redo());
+
+        history.undo();
+        history.add(Gas::O2, 10.0);
+        assert!(!history.redo());
+
+        let mut shallow = History::new(original, 2);
+        shallow.add(Gas::N2, 20.0);
+        let oldest_kept = shallow.current();
+        shallow.heat(1e6);
+        shallow.react();
+        assert!(shallow.undo() && shallow.undo());
+        assert!(!shallow.undo());
+        assert!(shallow.current().approx_equals(&oldest_kept, 1e-12));
+    }
+
+    #[test]
+    fn react_once_max_dt_test() {
+        let fire = gen_gas_mix_with_temp!(
+            with(Gas::Pl => 500.0, Gas::O2 => 6000.0,)
+            at(temperature!(5000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let unclamped = R::react_once(fire);
+        assert!(unclamped.temperature() - fire.temperature() > 500.0);
+        assert_eq!(R::react_once_max_dt(fire, 1e9), unclamped);
+
+        let clamped = R::react_once_max_dt(fire, 100.0);
+        assert!(approx_eq!(f64, clamped.temperature(), fire.temperature() + 100.0, epsilon = 1e-9));
+        assert!(clamped[Gas::Pl] < fire[Gas::Pl]);
+        assert!(clamped[Gas::Pl] > unclamped[Gas::Pl]);
+
+        let frozen = R::react_once_max_dt(fire, 0.0);
+        assert!(approx_eq!(f64, frozen.temperature(), fire.temperature(), epsilon = 1e-9));
+    }
+
+    #[test]
+    fn react_once_with_locked_test() {
+        let fire = gen_gas_mix_with_temp!(
+            with(Gas::Pl => 50.0, Gas::O2 => 20.0,)
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        assert_eq!(R::react_once_with_locked(fire, &[]), R::react_once(fire));
+
+        let mut cur = fire;
+        for _ in 0..10 {
+            let next = R::react_once_with_locked(cur, &[Gas::O2]);
+            assert_eq!(next[Gas::O2], fire[Gas::O2]);
+            assert!(next[Gas::Pl] < cur[Gas::Pl]);
+            assert!(next.temperature() > cur.temperature());
+            cur = next;
+        }
+        assert!(R::react_several(fire, 10)[9][Gas::O2] < fire[Gas::O2]);
+    }
+
+    #[test]
+    fn resolved_order_test() {
+        let mut set = R::ReactionSet::default();
+        for (name, priority) in [("first", -2), ("second", -1), ("third", 0), ("fourth", 1), ("fifth", 2)].iter() {
+            set.with_custom(name, *priority, Box::new(|gm: GasMixture| gm));
+        }
+        set.with_custom("tied", 0, Box::new(|gm: GasMixture| gm));
+
+        assert_eq!(set.resolved_order(), vec!["fifth", "fourth", "third", "tied", "second", "first"]);
+        assert_eq!(R::ReactionSet::standard().resolved_order(), R::STANDARD_REACTIONS.iter().map(|(name, _)| *name).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fusion_energy_translation_test() {
+        let scale_factor = C::FUSION_MINIMAL_SCALE;
+        for &e in &[0.0, -1e6, f64::NAN, f64::INFINITY] {
+            let translated = R::translate_fusion_energy(e, 1e9, scale_factor);
+            assert!(translated == e || (e.is_nan() && translated.is_nan()));
+        }
+        assert_eq!(R::translate_fusion_energy(1e9, 0.0, scale_factor), 1e9);
+        assert_eq!(R::translate_fusion_energy(1e9, f64::NAN, scale_factor), 1e9);
+        assert_eq!(R::translate_fusion_energy(f64::MAX / 2., 1e300, scale_factor), f64::MAX / 2.);
+
+        for &e in &[1e-300, 1e-6, 1.0, 1e3] {
+            let translated = R::translate_fusion_energy(e, 1e9, scale_factor);
+            assert!(translated.is_finite() && translated >= 0.0, "{} translated to {}", e, translated);
+        }
+
+        let cold = gen_gas_mix_with_temp!(
+            with(Gas::Pl => 500.0, Gas::CO2 => 500.0, Gas::H2 => 10.0,)
+            at(temperature!(1.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let fused = R::fusion::gated_at(cold, 0.0, C::MINIMUM_MOLE_COUNT);
+        let released_energy = fused.get_energy() - cold.get_energy();
+        assert!(released_energy.is_finite());
+        assert!(fused.temperature().is_finite());
+    }
+
+    #[test]
+    fn mix_temperatures_test() {
+        assert_eq!(mix_temperatures(100.0, 300.0, 100.0, 500.0), 400.0);
+        assert_eq!(mix_temperatures(300.0, 200.0, 100.0, 1000.0), 400.0);
+        assert_eq!(mix_temperatures(0.0, 300.0, 50.0, 1000.0), 1000.0);
+        assert_eq!(mix_temperatures(50.0, 300.0, 0.0, 1000.0), 300.0);
+        assert_eq!(mix_temperatures(0.0, 300.0, 0.0, 1000.0), 300.0);
+
+        let air = GasMixture::standard_air();
+        let plasma = gen_gas_mix_with_temp!(with(Gas::Pl => 30.0,) at(temperature!(900.0, K)));
+        let mixed = mix_temperatures(air.get_heat_cap(), air.temperature(), plasma.get_heat_cap(), plasma.temperature());
+        assert_eq!(air.mix_with(&plasma).temperature(), mixed);
+        assert_eq!(air.preview_merge_temperature(&plasma), mixed);
+    }
+
+    #[test]
+    fn react_until_done_fixed_point_test() {
+        let mut inputs: Vec<GasMixture> = reference_reactions()
+            .into_iter()
+            .filter(|(_, _, done)| done.is_some())
+            .map(|(input, _, _)| input)
+            .collect();
+        inputs.push(gen_gas_mix_with_temp!(
+            with(Gas::CO2 => 300.0, Gas::Pl => 300.0, Gas::H2 => 10.0,)
+            at(temperature!(20000.0, K))
+            in(C::CELL_VOLUME)
+        ));
+        // N2O decomposition only approaches its temperature cap, so it settles without ever becoming stable
+        let decomposing = gen_gas_mix_with_temp!(
+            with(Gas::N2 => 83.195, Gas::N2O => 287.5165,)
+            at(temperature!(81021.07478147968, K))
+            in(C::CELL_VOLUME)
+        );
+        inputs.push(decomposing);
+
+        for input in inputs {
+            let done = R::react_until_done(input);
+            let again = R::react_once(done);
+            assert!(again.approx_equals(&done, C::REACTION_SETTLE_TOLERANCE), "{:?} moved to {:?}", done, again);
+            if done.is_stable() {
+                assert_eq!(again, done);
+            }
+        }
+        assert!(!R::react_until_done(decomposing).is_stable());
+    }
+
+    #[test]
+    fn react_with_schedule_test() {
+        let fuel_rich = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 1.0,
+            )
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let oxygen_feed = [(10, Gas::O2, 200.0, C::T20C)];
+
+        let fed = R::react_with_schedule(fuel_rich, &oxygen_feed, 20);
+        let starved = R::react_several(fuel_rich, 20);
+        assert_eq!(fed.len(), 20);
+        assert_eq!(fed[..10], starved[..10]);
+
+        let burnt = |before: &GasMixture, after: &GasMixture| before[Gas::Pl] - after[Gas::Pl];
+        assert!(burnt(&fed[9], &fed[10]) > 10. * burnt(&starved[9], &starved[10]));
+        assert_eq!(fed[10].volume, C::CELL_VOLUME);
+        assert!(fed[19][Gas::CO2] > starved[19][Gas::CO2]);
+
+        assert_eq!(R::react_with_schedule(fuel_rich, &[(25, Gas::O2, 200.0, C::T20C)], 20), starved);
+    }
+
+    #[test]
+    fn plasma_fire_equilibrium_test() {
+        for &(pl, o2) in &[(100.0, 30.0), (100.0, 2.0), (500.0, 150.0), (20.0, 7.0)] {
+            let fire = gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => pl,
+                    Gas::O2 => o2,
+                    Gas::N2 => 100.0,
+                )
+                at(temperature!(2000.0, K))
+                in(C::CELL_VOLUME)
+            );
+            let predicted = analysis::plasma_fire_equilibrium(&fire).unwrap();
+            let settled = R::react_until_done(fire);
+
+            // Ticking may leave up to MINIMUM_MOLE_COUNT oxygen unburnt, along with the plasma it would burn
+            let unburnt = C::MINIMUM_MOLE_COUNT / (C::OXYGEN_BURN_RATE_BASE - 1.);
+            for &gas in ALL_GASES.iter() {
+                assert!((predicted[gas] - settled[gas]).abs() <= unburnt, "{:?}: {:?} != {:?}", gas, predicted, settled);
+            }
+            assert!((predicted.get_energy() - settled.get_energy()).abs() <= unburnt * C::FIRE_PLASMA_ENERGY_RELEASED);
+        }
+
+        let air = GasMixture::standard_air();
+        assert_eq!(analysis::plasma_fire_equilibrium(&air), Some(air));
+        let smothered = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 10.0,
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(2000.0, K))
+        );
+        assert_eq!(analysis::plasma_fire_equilibrium(&smothered), None);
+        assert_eq!(analysis::plasma_fire_equilibrium(&smothered.clone_at_temperature(1000.0).with_gas(Gas::Pl, 1000.0)), None);
+        let fusion = gen_gas_mix_with_temp!(
+            with(
+                Gas::CO2 => 2500.0,
+                Gas::Pl => 500.0,
+                Gas::H2 => 1.5,
+            )
+            at(temperature!(500000.0, K))
+        );
+        assert_eq!(analysis::plasma_fire_equilibrium(&fusion), None);
+
+        // Tritium burns alongside the plasma here, so a plasma-only estimate would be confidently wrong
+        let mixed_fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::O2 => 30.0,
+                Gas::H2 => 50.0,
+                Gas::N2 => 100.0,
+            )
+            at(temperature!(2000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        assert!(R::react_until_done(mixed_fuel)[Gas::H2O] > 0.0);
+        assert_eq!(analysis::plasma_fire_equilibrium(&mixed_fuel), None);
+    }
+
+    #[test]
+    fn react_once_explained_test() {
+        use crate::reactions::ReactionStatus;
+
+        let cold_fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 10.0,
+                Gas::O2 => 40.0,
+            )
+            at(C::T20C)
+            in(C::CELL_VOLUME)
+        );
+        let (result, explanation) = R::react_once_explained(cold_fuel);
+        assert_eq!(result, R::react_once(cold_fuel));
+        assert_eq!(explanation.len(), R::STANDARD_REACTIONS.len());
+        let status = |explanation: &[(&str, ReactionStatus)], name: &str| {
+            explanation.iter().find(|(reaction, _)| *reaction == name).unwrap().1
+        };
+        assert_eq!(
+            status(&explanation, "plasma_fire"),
+            ReactionStatus::BelowTemperature {
+                need: C::PLASMA_MINIMUM_BURN_TEMPERATURE,
+                have: C::T20C
+            }
+        );
+        assert_eq!(status(&explanation, "bz_synth"), ReactionStatus::InsufficientGas(Gas::N2O));
+
+        let burning = cold_fuel.clone_at_temperature(1000.0);
+        let (result, explanation) = R::react_once_explained(burning);
+        assert_eq!(result, R::react_once(burning));
+        assert_eq!(status(&explanation, "plasma_fire"), ReactionStatus::Fired);
+
+        let (_, explanation) = R::react_once_explained(burning.with_gas(Gas::HNb, 10.0));
+        assert_eq!(status(&explanation, "plasma_fire"), ReactionStatus::NobliumSuppressed);
+        assert_ne!(status(&explanation, "hnob_decomp"), ReactionStatus::NobliumSuppressed);
+    }
+
+    #[test]
+    fn heat_capacity_accessors_test() {
+        for &gas in ALL_GASES.iter() {
+            let pure = GasMixture::zero().with_gas(gas, 42.0);
+            assert!(approx_eq!(f64, pure.molar_heat_capacity(), gas.specific_heat(), ulps = 2));
+            assert!(approx_eq!(f64, pure.specific_heat_per_mass(), gas.specific_heat() / gas.molar_mass() * 1000., ulps = 4));
+        }
+
+        let air = GasMixture::standard_air();
+        assert!(approx_eq!(f64, air.molar_heat_capacity(), 20.0, ulps = 2));
+        assert_eq!(GasMixture::zero().molar_heat_capacity(), 0.0);
+        assert_eq!(GasMixture::zero().specific_heat_per_mass(), 0.0);
+    }
+
+    #[test]
+    fn react_each_until_done_stats_test() {
+        let air = GasMixture::standard_air();
+        let fire = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 50.0,
+                Gas::O2 => 100.0,
+            )
+            at(temperature!(500.0, K))
+        );
+        let noblium_cycle = gen_gas_mix_with_temp!(
+            with(
+                Gas::HNb => 20.0,
+            )
+            at(temperature!(2e7, K))
+        );
+
+        let (results, stats) = R::react_each_until_done_stats(vec![air, fire, air, noblium_cycle], C::REACTION_SETTLE_TOLERANCE);
+        assert_eq!(results[..3], [air, R::react_until_done(fire), air]);
+        assert_eq!(stats.min_ticks, 0);
+        assert_eq!(stats.max_ticks, C::BATCH_MAX_TICKS);
+        assert_eq!(stats.capped, 1);
+        assert!(stats.mean_ticks > 0.0 && stats.mean_ticks < C::BATCH_MAX_TICKS as f64);
+
+        let (_, fire_stats) = R::react_each_until_done_stats(vec![fire], C::REACTION_SETTLE_TOLERANCE);
+        assert!(fire_stats.min_ticks > 1);
+        assert_eq!(fire_stats.capped, 0);
+        assert_eq!(R::react_each_until_done_stats(Vec::new(), 0.0), (Vec::new(), R::BatchStats::default()));
+    }
+
+    #[test]
+    fn snapshot_string_test() {
+        let air = GasMixture::standard_air();
+        assert_eq!(
+            air.snapshot_string(3),
+            "temperature 293.150\npressure 101.325\nvolume 2500.000\ntotal_moles 103.984\nN2 82.147\nO2 21.837"
+        );
+        assert_eq!(air.snapshot_string(0).lines().next(), Some("temperature 293"));
+
+        let rounding_noise = GasMixture { volume: -1e-12, ..GasMixture::zero() };
+        assert!(rounding_noise.snapshot_string(3).contains("volume 0.000"));
+    }
+
+    reaction! (
+        called(plasma_compression)
+        with_present(
+            Gas::Pl
+        )
+        at(f64::NEG_INFINITY)
+        at_pressure(10. * C::ONE_ATMOSPHERE)
+        with_gm_as(gm) => {
+            gm + gen_gas_mix_with_energy!(
+                with(
+                    Gas::Pl => -1.0,
+                    Gas::PlOx => 1.0,
+                )
+                at(0.0)
+            )
+        }
+    );
+
+    #[test]
+    fn pressure_gate_test() {
+        let plasma = |volume: f64| {
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => 100.0,
+                )
+                at(temperature!(20.0, C))
+                in(volume)
+            )
+        };
+        let compressed = plasma(100.0);
+        let loose = plasma(C::CELL_VOLUME);
+        assert!(compressed.get_pressure() > plasma_compression::MIN_PRESSURE);
+        assert!(loose.get_pressure() < plasma_compression::MIN_PRESSURE);
+
+        assert_eq!(plasma_compression(compressed)[Gas::PlOx], 1.0);
+        assert_eq!(plasma_compression(loose), loose);
+        assert!(plasma_compression::SPEC.can_react(&compressed));
+        assert!(!plasma_compression::SPEC.can_react(&loose));
+        assert_eq!(plasma_compression::SPEC.blocked_by(&compressed), None);
+        assert_eq!(
+            plasma_compression::SPEC.blocked_by(&loose),
+            Some(R::ReactionStatus::BelowPressure {
+                need: plasma_compression::MIN_PRESSURE,
+                have: loose.get_pressure()
+            })
+        );
+        assert_eq!(
+            plasma_compression::SPEC.blocked_by(&compressed.with_gas(Gas::Pl, -100.0).with_gas(Gas::N2, 100.0)),
+            Some(R::ReactionStatus::InsufficientGas(Gas::Pl))
+        );
+
+        assert_eq!(pressurized_oxidation::MIN_PRESSURE, f64::NEG_INFINITY);
+        assert!(R::STANDARD_REACTION_SPECS
+            .iter()
+            .all(|spec| spec.min_pressure == f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn apply_command_test() {
+        let fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 10.0,
+                Gas::O2 => 40.0,
+            )
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+
+        assert_eq!(apply_command(fuel, "react"), Ok(R::react_once(fuel)));
+        assert_eq!(apply_command(fuel, "react 5"), Ok(R::react_several(fuel, 5)[4]));
+        assert_eq!(apply_command(fuel, "react 0"), Ok(fuel));
+        assert_eq!(apply_command(fuel, "heat 1000"), Ok(fuel.adjust_thermal_energy(1000.0)));
+        assert_eq!(apply_command(fuel, "  add  oxygen 50 "), Ok(fuel.with_gas(Gas::O2, 50.0)));
+        assert_eq!(apply_command(fuel, "add O2 50"), Ok(fuel.with_gas(Gas::O2, 50.0)));
+        assert_eq!(apply_command(fuel, "set_temp 400"), Ok(fuel.clone_at_temperature(400.0)));
+
+        assert!(apply_command(fuel, "").is_err());
+        assert!(apply_command(fuel, "explode").is_err());
+        assert!(apply_command(fuel, "react many").is_err());
+        assert!(apply_command(fuel, "add O2").is_err());
+        assert!(apply_command(fuel, "add air 50").is_err());
+        assert!(apply_command(GasMixture::zero(), "heat 1000").is_err());
+    }
+
+    /// Change in gases and thermal energy released by `reaction` on `gm`, which it must react.
+    fn bookkeeping(reaction: R::Reaction, gm: GasMixture) -> (GasVec, f64) {
+        let reacted = reaction(gm);
+        assert_ne!(reacted, gm, "reference mixture did not react");
+        (reacted.gases - gm.gases, reacted.get_energy() - gm.get_energy())
+    }
+
+    #[test]
+    fn reaction_bookkeeping_test() {
+        let close = |lhs: f64, rhs: f64| approx_eq!(f64, lhs, rhs, epsilon = 1e-9 * rhs.abs().max(1.0));
+
+        // Conservative reactions balance their atoms: N2O -> N2 + O2/2
+        let (delta, energy) = bookkeeping(R::n2o_decomp, gen_gas_mix_with_temp!(
+            with(Gas::N2O => 100.0,)
+            at(temperature!(1500.0, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::N2], -delta[Gas::N2O]));
+        assert!(close(2. * delta[Gas::O2], -delta[Gas::N2O]));
+        assert!(close(energy, -delta[Gas::N2O] * C::N2O_DECOMPOSITION_ENERGY_RELEASED));
+
+        let plasma_fire = gen_gas_mix_with_temp!(
+            with(Gas::Pl => 50.0, Gas::O2 => 100.0,)
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let temp_scale = ((1000.0 - C::PLASMA_MINIMUM_BURN_TEMPERATURE) / C::PLASMA_TEMP_SCALE).clamp(0., 1.);
+        let (delta, energy) = bookkeeping(R::plasma_fire, plasma_fire);
+        assert!(close(delta[Gas::CO2], -delta[Gas::Pl]));
+        assert!(close(delta[Gas::O2], delta[Gas::Pl] * (C::OXYGEN_BURN_RATE_BASE - temp_scale)));
+        assert!(close(energy, -delta[Gas::Pl] * C::FIRE_PLASMA_ENERGY_RELEASED));
+
+        let (delta, energy) = bookkeeping(R::trit_fire, gen_gas_mix_with_temp!(
+            with(Gas::H2 => 20.0, Gas::O2 => 100.0,)
+            at(temperature!(600.0, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::H2O], 20.0));
+        assert!(close(delta[Gas::H2], -20.0 / C::TRITIUM_BURN_TRIT_FACTOR));
+        assert!(close(delta[Gas::O2], -20.0 * (1. - 1. / C::TRITIUM_BURN_TRIT_FACTOR)));
+        assert!(close(energy, 20.0 * C::FIRE_HYDROGEN_ENERGY_RELEASED * C::TRITIUM_BURN_TRIT_FACTOR));
+
+        let (delta, energy) = bookkeeping(R::nitryl_formation, gen_gas_mix_with_temp!(
+            with(Gas::N2 => 50.0, Gas::O2 => 50.0, Gas::PlOx => 10.0,)
+            at(temperature!(2. * C::NITRYL_FORMATION_MIN_TEMPERATURE, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::N2], delta[Gas::O2]));
+        assert!(close(delta[Gas::NO2], -2. * delta[Gas::N2]));
+        assert!(close(energy, delta[Gas::N2] * C::NITRYL_FORMATION_ENERGY));
+
+        let (delta, energy) = bookkeeping(R::bz_synth, gen_gas_mix_with_temp!(
+            with(Gas::N2O => 15.0, Gas::Pl => 15.0,)
+            at(temperature!(300.0, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::Pl], 2. * delta[Gas::N2O]));
+        assert!(close(delta[Gas::BZ], -delta[Gas::N2O]));
+        assert!(close(energy, -2. * delta[Gas::N2O] * C::FIRE_CARBON_ENERGY_RELEASED));
+
+        let (delta, _) = bookkeeping(R::stimulum_synth, gen_gas_mix_with_temp!(
+            with(Gas::H2 => 50.0, Gas::Pl => 20.0, Gas::BZ => 50.0, Gas::NO2 => 50.0,)
+            at(temperature!(C::STIMULUM_FORMATION_MIN_TEMPERATURE * 1.5, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::ST], -delta[Gas::Pl] / 10.));
+        assert!(close(delta[Gas::NO2], delta[Gas::Pl]));
+        assert!(close(delta[Gas::H2], delta[Gas::Pl]));
+        assert_eq!(delta[Gas::BZ], 0.0);
+
+        let (delta, energy) = bookkeeping(R::stimulum_decay, gen_gas_mix_with_temp!(
+            with(Gas::ST => 10.0, Gas::N2 => 10.0,)
+            at(temperature!(2000.0, K))
+            in(C::CELL_VOLUME)
+        ));
+        assert!(close(delta[Gas::N2], -delta[Gas::ST]));
+        assert!(close(delta[Gas::O2], -delta[Gas::ST]));
+        assert!(close(energy, -delta[Gas::ST] * C::STIMULUM_DECAY_ENERGY_RELEASED));
+
+        // Noblium decomposition exactly undoes its synthesis, heat included
+        let (synthesized, synthesis_energy) = bookkeeping(R::hnob_synth, gen_gas_mix_with_temp!(
+            with(Gas::N2 => 25.0, Gas::H2 => 15.0,)
+            at(temperature!(5e6, K))
+            in(C::CELL_VOLUME)
+        ));
+        let (decomposed, energy_released) = bookkeeping(R::hnob_decomp, gen_gas_mix_with_temp!(
+            with(Gas::HNb => 10.0,)
+            at(temperature!(2e7, K))
+            in(C::CELL_VOLUME)
+        ));
+        for &(delta, energy) in &[(synthesized, synthesis_energy), (decomposed, energy_released)] {
+            assert!(close(delta[Gas::H2], -10. * delta[Gas::HNb]));
+            assert!(close(delta[Gas::N2], -20. * delta[Gas::HNb]));
+            assert!(close(energy, -delta[Gas::HNb] * C::NOBLIUM_FORMATION_ENERGY));
+        }
+
+        let fusion = gen_gas_mix_with_temp!(
+            with(Gas::CO2 => 2500.0, Gas::Pl => 500.0, Gas::H2 => 1.5,)
+            at(temperature!(500000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let (delta, _) = bookkeeping(R::fusion, fusion);
+        let scale_factor = (C::CELL_VOLUME / C::FUSION_SCALE_DIVISOR).max(C::FUSION_MINIMAL_SCALE);
+        assert!(close(delta[Gas::H2], -C::FUSION_TRITIUM_MOLES_USED));
+        assert!(close(delta[Gas::O2], scale_factor * C::FUSION_TRITIUM_CONVERSION_COEFFICIENT * C::FUSION_TRITIUM_MOLES_USED));
+    }
+
+    #[test]
+    fn react_once_with_heat_test() {
+        let cold_fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 10.0,
+                Gas::O2 => 40.0,
+            )
+            at(C::T20C)
+            in(C::CELL_VOLUME)
+        );
+        assert_eq!(R::react_once_with_heat(cold_fuel, 0.0), cold_fuel);
+
+        let heated = R::react_once_with_heat(cold_fuel, 2. * cold_fuel.energy_to_ignition());
+        assert!(heated[Gas::Pl] < cold_fuel[Gas::Pl]);
+        assert!(heated[Gas::CO2] > 0.0);
+
+        let frozen = R::react_once_with_heat(cold_fuel, -2. * cold_fuel.get_energy());
+        assert_eq!(frozen.temperature(), C::TCMB);
+        assert_eq!(R::react_once_with_heat(GasMixture::zero(), 1e6), GasMixture::zero());
+    }
+
+    #[test]
+    fn bz_synth_guard_test() {
+        for &(pl, volume) in &[(1e5, C::CELL_VOLUME), (20.01, 1e6), (1e3, 0.0), (20.01, f64::INFINITY)] {
+            let gm = gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => pl,
+                    Gas::N2O => 10.001,
+                )
+                at(C::T20C)
+                in(volume)
+            );
+            let reacted = R::bz_synth(gm);
+            assert!(reacted.temperature().is_finite(), "{:?} reacted into {:?}", gm, reacted);
+            assert!(ALL_GASES.iter().all(|&gas| reacted[gas].is_finite() && reacted[gas] >= 0.0), "{:?} reacted into {:?}", gm, reacted);
+            assert!(reacted[Gas::N2O] < gm[Gas::N2O]);
+        }
+    }
+
+    reaction! (
+        called(trace_threshold)
+        with(
+            Gas::N2O => C::MINIMUM_MOLE_COUNT
+        )
+        at(f64::NEG_INFINITY)
+        with_gm_as(gm) => {
+            gm.with_gas(Gas::N2O, -gm[Gas::N2O])
+        }
+    );
 
     #[test]
-    fn energy_merge_test_positive() {
-        let mix0 = gen_gas_mix_with_temp!(
+    fn present_requirements_test() {
+        assert_eq!(R::plasma_fire::SPEC.present, &[Gas::Pl, Gas::O2]);
+        assert!(R::fusion::SPEC.present.is_empty());
+        assert!(trace_threshold::SPEC.present.is_empty());
+
+        // A threshold that happens to equal MINIMUM_MOLE_COUNT stays a threshold when presence is judged lower
+        let trace = gen_gas_mix_with_temp!(with(Gas::N2O => C::MINIMUM_MOLE_COUNT / 2.,) at(C::T20C) in(C::CELL_VOLUME));
+        assert_eq!(trace_threshold(trace), trace);
+        assert_eq!(trace_threshold::gated_at(trace, f64::NEG_INFINITY, 1e-4), trace);
+        let reacted = R::n2o_decomp::gated_at(trace.clone_at_temperature(2000.0), f64::NEG_INFINITY, 1e-4);
+        assert!(reacted[Gas::N2O] < trace[Gas::N2O]);
+    }
+
+    #[test]
+    fn react_once_with_min_moles_test() {
+        let tiny_fire = gen_gas_mix_with_temp!(
             with(
-                Gas::Pl => 200.0,
+                Gas::Pl => 0.002,
+                Gas::O2 => 0.005,
             )
             at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
         );
+        assert_eq!(R::react_once(tiny_fire), tiny_fire);
+        assert_eq!(R::react_once_with_min_moles(tiny_fire, C::MINIMUM_MOLE_COUNT).get_total_amount(), 0.0);
 
-        let mix1 = gen_gas_mix_with_temp!(
+        let burnt = R::react_once_with_min_moles(tiny_fire, 1e-4);
+        assert!(burnt[Gas::Pl] < tiny_fire[Gas::Pl]);
+        assert!(burnt.temperature() > tiny_fire.temperature());
+
+        let air = GasMixture::standard_air();
+        assert_eq!(R::react_once_with_min_moles(air, C::MINIMUM_MOLE_COUNT), R::react_once(air));
+    }
+
+    #[test]
+    fn fusion_power_by_gas_test() {
+        let gm = gen_gas_mix_with_temp!(
             with(
-                Gas::Pl => 200.0,
+                Gas::H2 => 10.0,
+                Gas::N2O => 5.0,
+                Gas::PlOx => 2.0,
+                Gas::Pl => 500.0,
             )
-            at(temperature!(2000.0, K))
+            at(temperature!(50000.0, K))
         );
+        let by_gas = gm.fusion_power_by_gas();
+        assert!(approx_eq!(f64, by_gas.values().sum::<f64>(), gm.get_fusion_power(), ulps = 4));
+        assert_eq!(by_gas[Gas::N2O], 50.0);
+        assert_eq!(by_gas[Gas::PlOx], -20.0);
+        assert_eq!(by_gas[Gas::Pl], 0.0);
+    }
 
-        assert!(
-            approx_eq!(
-                f64,
-                (mix0 + mix1).get_energy(),
-                mix0.get_energy() + mix1.get_energy()
-            ),
-            "Energy is not conserved with positive mixes"
+    #[test]
+    fn safe_heat_headroom_test() {
+        let fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 10.0,
+                Gas::O2 => 40.0,
+            )
+            at(C::T20C)
+            in(C::CELL_VOLUME)
         );
+        let to_ignition = fuel.energy_to_ignition();
+        assert!(approx_eq!(f64, to_ignition, (C::PLASMA_MINIMUM_BURN_TEMPERATURE - C::T20C) * fuel.get_heat_cap(), epsilon = 1e-6));
+        assert_eq!(fuel.safe_heat_headroom(C::HAZARD_HIGH_PRESSURE), to_ignition);
+        assert_eq!(fuel.safe_heat_headroom(f64::INFINITY), to_ignition);
+        assert_eq!(fuel.adjust_thermal_energy(to_ignition).energy_to_ignition(), 0.0);
 
-        assert!(
-            approx_eq!(f64, (mix0 + mix1)[Gas::Pl], 400.0),
-            "Matter is not conserved with positive mixes"
+        let air = GasMixture::standard_air();
+        assert_eq!(air.energy_to_ignition(), f64::INFINITY);
+        let headroom = air.safe_heat_headroom(C::HAZARD_HIGH_PRESSURE);
+        assert!(headroom.is_finite());
+        assert!(approx_eq!(f64, air.adjust_thermal_energy(headroom).get_pressure(), C::HAZARD_HIGH_PRESSURE, epsilon = 1e-6));
+        assert_eq!(air.safe_heat_headroom(f64::INFINITY), f64::INFINITY);
+        assert_eq!(air.safe_heat_headroom(C::ONE_ATMOSPHERE / 2.), 0.0);
+    }
+
+    #[test]
+    fn reaction_hysteresis_test() {
+        let threshold = R::plasma_fire::MIN_TEMPERATURE;
+        let fuel = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 50.0,
+                Gas::O2 => 200.0,
+            )
+            at(temperature!(threshold + 1.0, K))
         );
+        let burning_ticks = |context: &mut R::ReactionContext| {
+            (0..10)
+                .map(|tick| {
+                    let offset = if tick % 2 == 0 { 1.0 } else { -1.0 };
+                    R::react_once_with_context(fuel.clone_at_temperature(threshold + offset), context);
+                    context.active().contains(&"plasma_fire")
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let flickering = burning_ticks(&mut R::ReactionContext::new(0.0));
+        assert_eq!(flickering, (0..10).map(|tick| tick % 2 == 0).collect::<Vec<_>>());
+        assert!(burning_ticks(&mut R::ReactionContext::default()).iter().all(|&burning| burning));
+
+        let mut cold_start = R::ReactionContext::default();
+        let below = fuel.clone_at_temperature(threshold - 1.0);
+        assert_eq!(R::react_once_with_context(below, &mut cold_start), below);
+        assert!(cold_start.active().is_empty());
+        assert_eq!(R::react_once_with_context(fuel, &mut cold_start), R::react_once(fuel));
     }
 
     #[test]
-    fn energy_merge_test_negative() {
-        let mix0 = gen_gas_mix_with_temp!(
+    fn from_moles_test() {
+        let gm = GasMixture::from_moles(&[(Gas::O2, 20.0), (Gas::N2, 80.0), (Gas::O2, 5.0)], C::T20C, C::CELL_VOLUME).unwrap();
+        assert_eq!(gm[Gas::O2], 25.0);
+        assert_eq!(gm[Gas::N2], 80.0);
+        assert_eq!(gm.temperature(), C::T20C);
+        assert_eq!(gm.volume, C::CELL_VOLUME);
+
+        assert_eq!(
+            GasMixture::from_moles(&[(Gas::Pl, 10.0), (Gas::CO2, -1.0)], C::T20C, C::CELL_VOLUME),
+            Err(ArithmeticError::NegativeAmount(Gas::CO2))
+        );
+        assert_eq!(GasMixture::from_moles(&[], C::T20C, 0.0), Ok(GasMixture::new(gen_gas_vec!(), C::T20C, 0.0)));
+    }
+
+    #[test]
+    fn tick_report_test() {
+        use crate::reactions::ReactionEvent;
+
+        let tritium_fire = gen_gas_mix_with_temp!(
             with(
-                Gas::Pl => 200.0,
+                Gas::H2 => 20.0,
+                Gas::O2 => 100.0,
+                Gas::N2 => 300.0,
             )
-            at(temperature!(1000.0, K))
+            at(temperature!(600.0, K))
         );
+        let report = tritium_fire.tick_report();
+        let (_, outcomes) = R::react_once_traced(tritium_fire);
+        assert_eq!(report.result, R::react_once(tritium_fire));
+        assert_eq!(report.reactions_fired, outcomes.iter().map(|outcome| outcome.name).collect::<Vec<_>>());
+        assert!(report.reactions_fired.contains(&"trit_fire"));
+        assert!(approx_eq!(f64, report.energy_released, report.result.get_energy() - tritium_fire.get_energy(), epsilon = 1e-3));
+        assert!(report.radiation > 0.0);
+        assert_eq!(report.events, R::react_once_events(tritium_fire).1);
 
-        let mix1 = gen_gas_mix_with_temp!(
+        let air = GasMixture::standard_air().tick_report();
+        assert!(air.reactions_fired.is_empty());
+        assert_eq!(air.radiation, 0.0);
+        assert!(!air.events.contains(&ReactionEvent::Ignition));
+    }
+
+    #[test]
+    fn release_canister_test() {
+        let room = GasMixture::standard_air();
+        let oxygen = gen_gas_mix_with_temp!(
+            with(Gas::O2 => 200.0,)
+            at(temperature!(80.0, K))
+            in(1000.0)
+        );
+
+        let (mut canister, mut opened) = (oxygen, room);
+        let released = release_canister(&mut canister, &mut opened);
+        assert!(released > 0.0);
+        assert!(approx_eq!(f64, canister.get_pressure(), opened.get_pressure(), epsilon = 1e-6));
+        assert!(approx_eq!(f64, canister.get_total_amount() + opened.get_total_amount(), 200.0 + room.get_total_amount(), epsilon = 1e-9));
+        assert!(approx_eq!(f64, canister.get_energy() + opened.get_energy(), oxygen.get_energy() + room.get_energy(), epsilon = 1e-6));
+        assert!(opened.temperature() < room.temperature());
+
+        let mut empty = GasMixture { volume: 1000.0, ..GasMixture::zero() };
+        assert_eq!(release_canister(&mut empty, &mut opened), 0.0);
+        assert_eq!(room.preview_merge_temperature(&oxygen), room.mix_with(&oxygen).temperature());
+    }
+
+    /// Relative tolerance the reference outputs are pinned to. Fusion goes through libm's log10, powf, log and sin,
+    /// whose last bits may differ between platforms, so the pins must not be bit-exact.
+    const REFERENCE_TOLERANCE: f64 = 1e-9;
+
+    /// Reference mixtures with their pinned `react_once` and `react_until_done` outputs.
+    /// Decomposing noblium cycles back into synthesis forever, so it has no settled output.
+    fn reference_reactions() -> Vec<(GasMixture, GasMixture, Option<GasMixture>)> {
+        vec![
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::Pl => 50.0, Gas::O2 => 100.0,)
+                    at(temperature!(500.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::O2 => 99.92785652568527, Gas::CO2 => 0.0554899387576553, Gas::Pl => 49.94451006124235,)
+                    at(temperature!(514.338653774157, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(
+                        Gas::O2 => 71.28081259389177,
+                        Gas::CO2 => 49.237454979537475,
+                        Gas::Pl => 0.009763873831883936,
+                        Gas::H2O => 7.432799007704627,
+                        Gas::H2 => 0.009501245860195828,
+                    )
+                    at(temperature!(55208.32109846616, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::H2 => 20.0, Gas::O2 => 100.0, Gas::N2 => 300.0,)
+                    at(temperature!(600.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 300.0, Gas::O2 => 82.0, Gas::H2O => 20.0, Gas::H2 => 18.0,)
+                    at(temperature!(7067.285382830627, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(
+                        Gas::N2 => 300.0,
+                        Gas::O2 => 0.006486025941761765,
+                        Gas::H2O => 119.93828283331655,
+                        Gas::H2 => 0.05523114074435805,
+                    )
+                    at(temperature!(29494.19020883973, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2O => 40.0, Gas::N2 => 60.0,)
+                    at(temperature!(1500.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 61.182, Gas::O2 => 0.591, Gas::N2O => 38.818,)
+                    at(temperature!(1591.1454784124412, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 99.99051278335972, Gas::O2 => 19.99525639167988, Gas::N2O => 0.00948721664023746,)
+                    at(temperature!(5082.341826621863, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::CO2 => 2500.0, Gas::Pl => 500.0, Gas::H2 => 1.5,)
+                    at(temperature!(500000.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::O2 => 0.1, Gas::CO2 => 460.81379150257, Gas::Pl => 308.54670697982334, Gas::H2O => 0.1, Gas::H2 => 0.5,)
+                    at(temperature!(1870892.8442544553, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(Gas::CO2 => 461.06379150257, Gas::Pl => 308.29670697982334, Gas::H2O => 0.101, Gas::H2 => 0.499,)
+                    at(temperature!(1872004.7458138624, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2O => 10.0, Gas::Pl => 20.0, Gas::NO2 => 5.0,)
+                    at(temperature!(400.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2O => 9.999999782265343, Gas::Pl => 19.999999564530686, Gas::NO2 => 5.0, Gas::BZ => 2.1773465703971114e-7,)
+                    at(temperature!(400.00001819294056, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(Gas::N2O => 9.999999782265343, Gas::Pl => 19.999999564530686, Gas::NO2 => 5.0, Gas::BZ => 2.1773465703971114e-7,)
+                    at(temperature!(400.00001819294056, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 25.0, Gas::H2 => 15.0, Gas::BZ => 2.0,)
+                    at(temperature!(5000000.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 17.0, Gas::HNb => 0.4, Gas::H2 => 11.0, Gas::BZ => 2.0,)
+                    at(temperature!(2280000.0, K))
+                ),
+                Some(gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 17.0, Gas::HNb => 0.4, Gas::H2 => 11.0, Gas::BZ => 2.0,)
+                    at(temperature!(2280000.0, K))
+                )),
+            ),
+            (
+                gen_gas_mix_with_temp!(
+                    with(Gas::HNb => 20.0,)
+                    at(temperature!(20000000.0, K))
+                ),
+                gen_gas_mix_with_temp!(
+                    with(Gas::N2 => 4.0, Gas::HNb => 19.8, Gas::H2 => 2.0,)
+                    at(temperature!(20161209.068010077, K))
+                ),
+                None,
+            ),
+        ]
+    }
+
+    #[test]
+    fn reference_reactions_test() {
+        for (input, once, done) in reference_reactions() {
+            let reacted = R::react_once(input);
+            assert!(reacted.approx_equals(&once, REFERENCE_TOLERANCE), "{:?} reacted into {:?}", input, reacted);
+            if let Some(done) = done {
+                let settled = R::react_until_done(input);
+                assert!(settled.approx_equals(&done, REFERENCE_TOLERANCE), "{:?} settled into {:?}", input, settled);
+            }
+        }
+
+        let air = GasMixture::standard_air();
+        assert!(air.approx_equals(&air.clone_at_temperature(air.temperature() * (1. + 1e-12)), 1e-9));
+        assert!(!air.approx_equals(&air.clone_at_temperature(air.temperature() + 1.0), 1e-9));
+    }
+
+    #[test]
+    fn average_with_test() {
+        let air = GasMixture::standard_air();
+        let mut tile = air;
+        tile.average_with(&[&air, &air, &air, &air], 2.0);
+        assert!(approx_eq!(f64, tile.get_total_amount(), air.get_total_amount(), ulps = 4));
+        assert!(approx_eq!(f64, tile.temperature(), air.temperature(), ulps = 4));
+
+        let hot = air.clone_at_temperature(1000.0);
+        let mut cold = air;
+        cold.average_with(&[&hot], 1.0);
+        assert!(approx_eq!(f64, cold.get_energy(), (air.get_energy() + hot.get_energy()) / 2., epsilon = 1e-6));
+        assert_eq!(hot, air.clone_at_temperature(1000.0));
+    }
+
+    #[test]
+    fn react_once_events_test() {
+        use crate::reactions::ReactionEvent;
+
+        let smouldering = gen_gas_mix_with_temp!(
             with(
-                Gas::Pl => -50.0,
+                Gas::Pl => 10.0,
+                Gas::N2O => 10.0,
             )
-            at(temperature!(2000.0, K))
+            at(temperature!(1500.0, K))
         );
+        let (burning, events) = R::react_once_events(smouldering);
+        assert!(events.contains(&ReactionEvent::Ignition));
+        assert!(!R::react_once_events(burning).1.contains(&ReactionEvent::Ignition));
 
-        assert!(
-            approx_eq!(
-                f64,
-                (mix0 + mix1).get_energy(),
-                mix0.get_energy() + mix1.get_energy()
+        let synthesizing = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 25.0,
+                Gas::H2 => 15.0,
+                Gas::HNb => 4.8,
+            )
+            at(temperature!(5000000.0, K))
+        );
+        let (locked, events) = R::react_once_events(synthesizing);
+        assert_eq!(events, vec![ReactionEvent::NobliumFormed]);
+        assert!(locked[Gas::HNb] >= C::NOBLIUM_SUPPRESSION_THRESHOLD);
+        assert!(R::react_once_events(locked).1.is_empty());
+    }
+
+    #[test]
+    fn recompute_test() {
+        let air = GasMixture::standard_air();
+
+        let mut corrupted = GasMixture::from_parts(air.gases, -40.0, air.volume);
+        assert_eq!(corrupted.temperature(), -40.0);
+        corrupted.recompute_from_temperature();
+        assert_eq!(corrupted.temperature(), C::TCMB);
+
+        let mut enriched = GasMixture {
+            gases: air.gases + gen_gas_vec!(Gas::CO2 => 20.0,),
+            ..air
+        };
+        assert!(enriched.get_energy() > air.get_energy());
+        enriched.recompute_from_energy(air.get_energy());
+        assert!(approx_eq!(f64, enriched.get_energy(), air.get_energy(), epsilon = 1e-6));
+        assert!(enriched.temperature() < air.temperature());
+
+        let mut empty = GasMixture::zero();
+        empty.recompute_from_energy(1e6);
+        assert_eq!(empty, GasMixture::zero());
+    }
+
+    #[test]
+    fn tiny_volume_test() {
+        for volume in [0.0, 1e-12, C::MINIMUM_VOLUME].iter() {
+            let gm = gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => 500.0,
+                    Gas::CO2 => 500.0,
+                    Gas::H2 => 500.0,
+                    Gas::O2 => 500.0,
+                    Gas::N2O => 50.0,
+                )
+                at(temperature!(2e4, K))
+                in(*volume)
+            );
+            let pressure = gm.get_pressure();
+            assert!(pressure.is_finite() && pressure > 0.0);
+            assert_eq!(pressure, gm.pressure_after_temperature(gm.temperature()));
+
+            let result = R::react_once(gm);
+            assert!(result.temperature().is_finite(), "volume {}", volume);
+            assert!(result.gases.0.values().all(|amount| amount.is_finite() && *amount >= 0.0));
+            assert!(result.get_pressure().is_finite());
+        }
+
+        assert_eq!(GasMixture::zero().get_pressure(), 0.0);
+    }
+
+    #[test]
+    fn catalyst_test() {
+        assert_eq!(R::hnob_synth::CATALYSTS, &[Gas::BZ]);
+        assert!(R::STANDARD_REACTION_SPECS
+            .iter()
+            .all(|spec| spec.name == "hnob_synth" || spec.catalysts.is_empty()));
+
+        let plain = gen_gas_mix_with_temp!(
+            with(
+                Gas::N2 => 1000.0,
+                Gas::H2 => 500.0,
+            )
+            at(temperature!(1e7, K))
+            in(C::CELL_VOLUME)
+        );
+        let catalyzed = plain.with_gas(Gas::BZ, 100.0);
+
+        let plain_result = R::hnob_synth(plain);
+        let catalyzed_result = R::hnob_synth(catalyzed);
+        assert_eq!(catalyzed_result[Gas::BZ], catalyzed[Gas::BZ]);
+        assert_eq!(catalyzed_result[Gas::HNb], plain_result[Gas::HNb]);
+
+        let energy_used = |before: &GasMixture, after: &GasMixture| before.get_energy() - after.get_energy();
+        assert!(approx_eq!(
+            f64,
+            energy_used(&catalyzed, &catalyzed_result) * 100.0,
+            energy_used(&plain, &plain_result),
+            epsilon = 1e-3
+        ));
+    }
+
+    #[test]
+    fn partial_pressure_array_test() {
+        let gm = GasMixture::standard_air().with_gas(Gas::Pl, 5.0);
+        let pressures = gm.partial_pressure_array();
+
+        assert_eq!(pressures.len(), GAS_COUNT);
+        assert!(approx_eq!(f64, pressures.iter().sum::<f64>(), gm.get_pressure(), epsilon = 1e-9));
+        assert_eq!(pressures[0], gm.partial_pressure(Gas::N2));
+        assert_eq!(pressures[1], gm.partial_pressure(Gas::O2));
+        assert_eq!(pressures[4], gm.partial_pressure(Gas::Pl));
+        for (index, gas) in ALL_GASES.iter().enumerate() {
+            assert_eq!(pressures[index], gm.partial_pressure(*gas));
+        }
+    }
+
+    #[test]
+    fn react_once_audited_test() {
+        // Reactants right at their thresholds, starved of their co-reactant, or far in excess at extreme temperatures
+        let crafted = [
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => C::MINIMUM_MOLE_COUNT,
+                    Gas::O2 => C::MINIMUM_MOLE_COUNT,
+                    Gas::H2 => C::MINIMUM_MOLE_COUNT,
+                    Gas::N2O => C::MINIMUM_MOLE_COUNT,
+                )
+                at(temperature!(1e9, K))
+                in(C::CELL_VOLUME)
             ),
-            "Energy is not conserved with negative mixes"
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::Pl => 1e6,
+                    Gas::O2 => 1e-3,
+                    Gas::H2 => 1e6,
+                    Gas::CO2 => 1e6,
+                    Gas::N2 => 10.0,
+                    Gas::NO2 => 30.0,
+                    Gas::BZ => 20.0,
+                    Gas::N2O => 10.0,
+                    Gas::PlOx => 5.0,
+                )
+                at(temperature!(1e7, K))
+                in(1.0)
+            ),
+        ];
+
+        for gm in crafted.iter().chain(fuzz_mixtures(10_000, 0x5851_f42d_4c95_7f2d).iter()) {
+            assert_eq!(R::react_once_audited(*gm), R::react_once(*gm));
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overdraw used 20 moles of O2 with only 10 available")]
+    fn react_once_audited_overdraw_test() {
+        let overdraw: R::Reaction = |gm| gm.with_gas(Gas::O2, -2. * gm[Gas::O2]);
+        R::ReactionSet::default()
+            .with("overdraw", overdraw)
+            .react_once_audited(GasMixture::standard_air().portion(0.0).with_gas(Gas::O2, 10.0));
+    }
+
+    #[test]
+    fn reaction_set_custom_test() {
+        let air = GasMixture::standard_air();
+        let mut set = R::ReactionSet::standard();
+        set.with_custom(
+            "oxygenate",
+            1,
+            Box::new(|gm: GasMixture| {
+                let nitrogen = gm[Gas::N2];
+                gm.with_gas(Gas::N2, -nitrogen).with_gas(Gas::O2, nitrogen)
+            }),
         );
+        set.with_custom("fade", -1, Box::new(|gm: GasMixture| gm.portion(0.5)))
+            .with_custom("late_fade", -1, Box::new(|gm: GasMixture| gm.portion(0.5)));
 
-        assert!(
-            approx_eq!(f64, (mix0 + mix1)[Gas::Pl], 150.0),
-            "Matter is not conserved with negative mixes"
+        let names = set.names();
+        assert_eq!(names[0], "oxygenate");
+        assert_eq!(&names[names.len() - 2..], &["fade", "late_fade"]);
+        assert_eq!(names[1..names.len() - 2], R::ReactionSet::standard().names()[..]);
+
+        let result = set.react_once(air);
+        assert_eq!(result[Gas::N2], 0.0);
+        assert!(approx_eq!(f64, result[Gas::O2], air.get_total_amount() / 4.));
+    }
+
+    #[test]
+    fn power_output_test() {
+        assert_eq!(joules_per_tick_to_watts(1e6, C::ATMOS_TICK_SECONDS), 2e6);
+        assert_eq!(joules_per_tick_to_watts(300.0, 2.0), 150.0);
+
+        let fire = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 50.0)
+            .clone_at_temperature(1000.0);
+        let (_, heat) = R::react_once_isothermal(fire);
+        assert!(fire.estimated_power_output() > 0.0);
+        assert_eq!(fire.estimated_power_output(), heat / C::ATMOS_TICK_SECONDS);
+        assert_eq!(GasMixture::standard_air().estimated_power_output(), 0.0);
+    }
+
+    #[test]
+    fn split_into_test() {
+        let canister = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 30.0)
+            .clone_at_temperature(400.0);
+        let parts = canister.split_into(4);
+
+        assert_eq!(parts.len(), 4);
+        assert!(parts.iter().all(|part| part.temperature() == canister.temperature()));
+        assert!(approx_eq!(f64, parts[0].volume, canister.volume / 4.));
+        assert_conserves_moles_and_energy(&[canister], &parts, 1e-9);
+
+        assert!(canister.split_into(0).is_empty());
+        assert_eq!(canister.split_into(1), vec![canister]);
+    }
+
+    #[test]
+    fn net_direction_test() {
+        // At 1e5 K stimulum_synth forms 0.1 moles of stimulum per tick and stimulum_decay breaks down 5% of it, balancing at 2 moles
+        let chamber = |stimulum: f64| {
+            gen_gas_mix_with_temp!(
+                with(
+                    Gas::H2 => 100.0,
+                    Gas::Pl => 100.0,
+                    Gas::BZ => 100.0,
+                    Gas::NO2 => 100.0,
+                    Gas::ST => stimulum,
+                )
+                at(temperature!(1e5, K))
+                in(C::CELL_VOLUME)
+            )
+        };
+
+        let balanced = R::net_direction("stimulum_synth", "stimulum_decay", &chamber(2.0));
+        assert!(balanced.abs() < 1e-9, "{}", balanced);
+        assert!(R::net_direction("stimulum_synth", "stimulum_decay", &chamber(1.0)) > 0.0);
+        assert!(R::net_direction("stimulum_synth", "stimulum_decay", &chamber(10.0)) < 0.0);
+
+        assert_eq!(R::net_direction("no_such_reaction", "stimulum_decay", &chamber(2.0)), 0.0);
+        assert!(R::reaction_delta("no_such_reaction", &chamber(2.0)).is_none());
+    }
+
+    #[test]
+    fn gas_present_test() {
+        let residual = GasMixture::standard_air().with_gas(Gas::Pl, 1e-12);
+        assert!(!residual.gas_present(Gas::Pl, C::MINIMUM_MOLE_COUNT));
+        assert!(residual.gas_present(Gas::Pl, 1e-13));
+        assert!(residual.gas_present(Gas::O2, C::MINIMUM_MOLE_COUNT));
+        assert!(!residual.gas_present(Gas::CO2, 0.0));
+
+        let summary = residual.summary();
+        assert!(summary.gases.iter().all(|(gas, _)| *gas != Gas::Pl));
+        assert_eq!(summary.gases.len(), 2);
+        assert_eq!(summary.total_moles, residual.get_total_amount());
+    }
+
+    #[test]
+    fn react_until_done_timed_test() {
+        use std::time::Duration;
+
+        let fire = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 50.0)
+            .clone_at_temperature(1000.0);
+        let done = R::react_until_done(fire);
+
+        let (partial, converged) = R::react_until_done_timed(fire, Duration::from_nanos(1));
+        assert!(!converged);
+        assert_ne!(partial, done);
+        assert!(partial[Gas::Pl] < fire[Gas::Pl]);
+
+        assert_eq!(R::react_until_done_timed(fire, Duration::from_secs(60)), (done, true));
+        let air = GasMixture::standard_air();
+        assert_eq!(R::react_until_done_timed(air, Duration::from_nanos(0)), (air, true));
+    }
+
+    #[test]
+    fn reaction_log_test() {
+        let gm = gen_gas_mix_with_temp!(
+            with(
+                Gas::Pl => 100.0,
+                Gas::H2 => 100.0,
+                Gas::O2 => 2000.0,
+                Gas::N2O => 50.0,
+            )
+            at(temperature!(1500.0, K))
+            in(C::CELL_VOLUME)
         );
+
+        let mut log = R::ReactionLog::new();
+        let recorded = (0..20).fold(gm, |cur, _| R::react_once_logged(cur, &mut log));
+        assert_eq!(recorded, *R::react_several(gm, 20).last().unwrap());
+        assert!(log.outcomes.len() >= 20);
+
+        let replayed = log.replay(gm);
+        assert!(approx_eq!(f64, replayed.temperature(), recorded.temperature(), epsilon = 1e-6));
+        for gas in ALL_GASES.iter() {
+            assert!(approx_eq!(f64, replayed[*gas], recorded[*gas], epsilon = 1e-9));
+        }
+        assert_eq!(R::ReactionLog::new().replay(gm), gm);
     }
 
     #[test]
-    #[should_panic]
-    fn energy_test_null_mix() {
-        gen_gas_mix_with_temp!(
+    fn temperature_accessor_test() {
+        let mut gm = GasMixture::new(gen_gas_vec!(Gas::O2 => 20.0,), 500.0, 2500.0);
+        assert_eq!(gm.temperature(), 500.0);
+
+        gm.set_temperature(1.0);
+        assert_eq!(gm.temperature(), C::TCMB);
+        assert_eq!(gm.adjust_thermal_energy(gm.get_heat_cap() * 100.0).temperature(), C::TCMB + 100.0);
+        assert!(R::react_once(gm.with_gas(Gas::Pl, 10.0).clone_at_temperature(1000.0)).temperature() > 1000.0);
+    }
+
+    #[test]
+    fn reaction_rounding_negative_test() {
+        // Found by react_once_fuzz_test: burning or binding all of a reactant used to leave a tiny negative amount behind
+        let oxygen_starved_fire = GasMixture {
+            gases: gen_gas_vec!(
+                Gas::O2 => 0.48590398938460483,
+                Gas::CO2 => 16005441.816367028,
+                Gas::Pl => 12606.540868946186,
+                Gas::H2O => 27.57313260401933,
+                Gas::HNb => 1879102.9298810398,
+                Gas::NO2 => 0.0006977461504858448,
+                Gas::H2 => 15135.196568656796,
+                Gas::ST => 1.7332655548688028e-9,
+                Gas::PlOx => 0.6077904819813345,
+            ),
+            temperature: 706416.4104163044,
+            volume: 81452.35134590034,
+        };
+        let tritium_starved_synthesis = GasMixture {
+            gases: gen_gas_vec!(
+                Gas::N2 => 48601.73614260693,
+                Gas::O2 => 118148.70497246414,
+                Gas::CO2 => 0.11795964971347049,
+                Gas::N2O => 1.0625556732953774e-9,
+                Gas::Pl => 47.17162175618687,
+                Gas::H2O => 1.0573308335462917e-6,
+                Gas::NO2 => 5568.948247675024,
+                Gas::H2 => 5.896452719523359,
+                Gas::BZ => 3.097125335456544e-7,
+                Gas::ST => 894741.407472771,
+                Gas::PlOx => 2.617173228458679,
+            ),
+            temperature: 123631490.90859932,
+            volume: 4.228442499313997,
+        };
+
+        let burnt = R::plasma_fire(oxygen_starved_fire);
+        assert_eq!(burnt[Gas::O2], 0.0);
+        let bound = R::hnob_synth(tritium_starved_synthesis);
+        assert_eq!(bound[Gas::H2], 0.0);
+        assert!(bound[Gas::HNb] > 0.0);
+    }
+
+    #[test]
+    fn scrub_plan_test() {
+        let stuffy = GasMixture::standard_air().with_gas(Gas::CO2, 20.0);
+        let plan = stuffy.scrub_plan(&[(Gas::CO2, 1.0), (Gas::O2, 30.0)]);
+        assert!(matches!(plan[0], ScrubAction::Remove(Gas::CO2, moles) if moles > 0.0));
+        assert!(matches!(plan[1], ScrubAction::Add(Gas::O2, moles) if moles > 0.0));
+
+        let apply = |gm: &GasMixture, plan: &[ScrubAction]| {
+            let mut scrubbed = *gm;
+            for action in plan.iter() {
+                match *action {
+                    ScrubAction::Remove(gas, moles) => scrubbed.add_gas(gas, -moles),
+                    ScrubAction::Add(gas, moles) => scrubbed.add_gas(gas, moles),
+                }
+            }
+            scrubbed
+        };
+        let scrubbed = apply(&stuffy, &plan);
+        assert!(approx_eq!(f64, scrubbed.partial_pressure(Gas::CO2), 1.0, epsilon = 1e-9));
+        assert!(approx_eq!(f64, scrubbed.partial_pressure(Gas::O2), 30.0, epsilon = 1e-9));
+        assert_eq!(scrubbed[Gas::N2], stuffy[Gas::N2]);
+
+        // Tiny volumes are planned over the same clamped volume partial pressures are read over
+        let tiny = GasMixture { volume: 1e-9, ..stuffy.portion(1e-6) };
+        let targets = [(Gas::CO2, 1.0), (Gas::O2, 1e5)];
+        let scrubbed = apply(&tiny, &tiny.scrub_plan(&targets));
+        for (gas, pressure) in targets.iter() {
+            assert!(approx_eq!(f64, scrubbed.partial_pressure(*gas), *pressure, epsilon = 1e-6));
+        }
+    }
+
+    #[test]
+    fn grid_superconduct_test() {
+        let cold = GasMixture::standard_air();
+        let mut grid = Grid::new(5, 5, cold);
+        grid[(2, 2)] = cold.clone_at_temperature(5000.0);
+        let total_energy = |grid: &Grid| grid.tiles().iter().map(GasMixture::get_energy).sum::<f64>();
+        let initial_energy = total_energy(&grid);
+
+        grid.superconduct(0.2);
+        for neighbour in [(1, 2), (3, 2), (2, 1), (2, 3)].iter() {
+            assert!(grid[*neighbour].temperature > cold.temperature);
+        }
+        assert!(grid[(2, 2)].temperature < 5000.0);
+
+        assert_eq!(grid[(0, 0)].temperature, cold.temperature);
+        for _ in 0..200 {
+            grid.superconduct(0.2);
+        }
+        let equilibrium = cold.temperature + (5000.0 - cold.temperature) / 25.;
+        for tile in grid.tiles() {
+            assert!(approx_eq!(f64, tile.temperature, equilibrium, epsilon = 1.0));
+        }
+        assert!(approx_eq!(f64, total_energy(&grid), initial_energy, epsilon = initial_energy * 1e-12));
+    }
+
+    #[test]
+    fn water_vapor_moderates_fire_test() {
+        // Water vapor takes part in no reaction, as in SS13, but its heat capacity soaks up fire heat
+        assert_eq!(Gas::H2O.specific_heat(), 40.);
+
+        let fire = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 50.0)
+            .clone_at_temperature(1000.0);
+        let steamy = fire.with_gas(Gas::H2O, 200.0);
+
+        let dry_result = R::react_once(fire);
+        let steamy_result = R::react_once(steamy);
+        assert_eq!(steamy_result[Gas::H2O], steamy[Gas::H2O]);
+        assert!(steamy_result.temperature < dry_result.temperature);
+        assert!(steamy_result.temperature > steamy.temperature);
+    }
+
+    #[test]
+    fn react_once_temp_capped_test() {
+        let fire = GasMixture::standard_air()
+            .with_gas(Gas::Pl, 50.0)
+            .clone_at_temperature(1000.0);
+        let uncapped = R::react_once(fire);
+
+        for max_temp in [1000.0, 1200.0, uncapped.temperature * 2.].iter() {
+            let (capped, dumped) = R::react_once_temp_capped(fire, *max_temp);
+            let overshoot = (uncapped.temperature - max_temp).max(0.0);
+
+            assert!(capped.temperature <= *max_temp);
+            assert_eq!(capped.gases, uncapped.gases);
+            assert!(approx_eq!(
+                f64,
+                dumped,
+                overshoot * capped.get_heat_cap(),
+                epsilon = 1e-6
+            ));
+        }
+    }
+
+    #[test]
+    fn hazards_test() {
+        let air = GasMixture::standard_air();
+        assert!(air.hazards().is_empty());
+
+        let stuffy = air.with_gas(Gas::CO2, 1.0);
+        assert!(stuffy.partial_pressure(Gas::CO2) < C::HAZARD_CO2_PARTIAL_PRESSURE);
+        assert!(stuffy.hazards().is_empty());
+
+        let mut strict = AlarmThresholds::default();
+        strict.max_partial_pressure[Gas::CO2] = 0.5;
+        strict.max_temperature = C::T20C - 1.;
+        assert_eq!(
+            stuffy.hazards_with(&strict),
+            vec![Hazard::HighTemperature, Hazard::ExcessGas(Gas::CO2)]
+        );
+
+        let vacuum = GasMixture {
+            volume: C::CELL_VOLUME,
+            ..GasMixture::zero()
+        };
+        assert_eq!(vacuum.hazards(), vec![Hazard::LowPressure, Hazard::LowTemperature]);
+    }
+
+    #[test]
+    fn react_once_isothermal_test() {
+        let gm = gen_gas_mix_with_temp!(
             with(
-                Gas::Pl => -50.0,
-                Gas::O2 => 50.0,
+                Gas::Pl => 100.0,
+                Gas::O2 => 1000.0,
             )
-            at(temperature!(2000.0, K))
+            at(temperature!(1000.0, K))
+            in(C::CELL_VOLUME)
+        );
+        let adiabatic = R::react_once(gm);
+        let (isothermal, heat) = R::react_once_isothermal(gm);
+
+        assert!(adiabatic.temperature > gm.temperature);
+        assert_eq!(isothermal.temperature, gm.temperature);
+        assert_eq!(isothermal.gases, adiabatic.gases);
+        assert!(heat > 0.0);
+        assert!(approx_eq!(
+            f64,
+            isothermal.get_energy() + heat,
+            adiabatic.get_energy(),
+            epsilon = 1e-6
+        ));
+
+        let inert = GasMixture::standard_air();
+        assert_eq!(R::react_once_isothermal(inert), (inert, 0.0));
+    }
+
+    /// Fixed-volume container storing its contents as bare totals, to check `GasSource` is implementable outside `GasMixture`.
+    #[derive(Clone)]
+    struct TestCanister {
+        contents: GasVec,
+        energy: f64,
+        volume: f64,
+    }
+
+    impl GasSource for TestCanister {
+        fn remove(&mut self, moles: f64) -> GasMixture {
+            let fraction = (moles / self.total_moles()).min(1.0);
+            let removed = GasMixture::with_energy(self.contents * fraction, self.energy * fraction, 0.0);
+            self.contents = self.contents * (1. - fraction);
+            self.energy *= 1. - fraction;
+            removed
+        }
+
+        fn merge(&mut self, gm: GasMixture) {
+            self.contents = self.contents + gm.gases;
+            self.energy += gm.get_energy();
+        }
+
+        fn get_pressure(&self) -> f64 {
+            C::R_IDEAL_GAS_EQUATION * self.energy / self.contents.get_heat_cap() * self.total_moles() / self.volume
+        }
+
+        fn total_moles(&self) -> f64 {
+            self.contents.get_total_amount()
+        }
+    }
+
+    #[test]
+    fn gas_source_test() {
+        let mut room = GasMixture::standard_air();
+        let air = GasMixture::standard_air();
+        let mut canister = TestCanister {
+            contents: air.gases * 10.,
+            energy: air.get_energy() * 10.,
+            volume: C::CELL_VOLUME,
+        };
+        let total = room.get_total_amount() + canister.total_moles();
+
+        let moved = pump_moles(&mut canister, &mut room, 50.0);
+        assert!(approx_eq!(f64, moved, 50.0, epsilon = 1e-9));
+        assert!(approx_eq!(f64, room.get_total_amount() + canister.total_moles(), total, epsilon = 1e-9));
+        assert_eq!(room.volume, C::CELL_VOLUME);
+        assert!(approx_eq!(f64, room.temperature, C::T20C, epsilon = 1e-9));
+
+        let target = 2. * C::ONE_ATMOSPHERE;
+        transfer_until_pressure(&mut canister, &mut room, target);
+        assert!(approx_eq!(f64, GasSource::get_pressure(&room), target, epsilon = 1e-6));
+        assert!(approx_eq!(f64, room.get_total_amount() + canister.total_moles(), total, epsilon = 1e-9));
+
+        assert_eq!(transfer_until_pressure(&mut canister, &mut room, C::ONE_ATMOSPHERE), 0.0);
+        let mut nearly_empty = room.portion(0.001);
+        nearly_empty.volume = C::CELL_VOLUME;
+        let drained = transfer_until_pressure(&mut nearly_empty, &mut canister, f64::INFINITY);
+        assert!(approx_eq!(f64, drained, room.get_total_amount() * 0.001, epsilon = 1e-9));
+        assert_eq!(nearly_empty.get_total_amount(), 0.0);
+    }
+
+    #[test]
+    fn gas_source_zero_heat_cap_test() {
+        let bz_tank = gen_gas_mix_with_temp!(with(Gas::BZ => 10.0,) at(C::T20C) in(C::CELL_VOLUME));
+
+        let mut tank = bz_tank;
+        tank.merge(GasMixture::standard_air());
+        assert_eq!(tank[Gas::BZ], 10.0);
+        assert_eq!(tank.temperature(), C::T20C);
+
+        let mut source = bz_tank;
+        let mut room = GasMixture::standard_air();
+        let total = source.get_total_amount() + room.get_total_amount();
+        let moved = pump_moles(&mut source, &mut room, 5.0);
+        assert_eq!(moved, 5.0);
+        assert_eq!(room[Gas::BZ], 5.0);
+        assert!(approx_eq!(f64, source.get_total_amount() + room.get_total_amount(), total, epsilon = 1e-9));
+        assert_eq!(room.temperature(), C::T20C);
+
+        let mut empty = GasMixture::zero();
+        empty.volume = C::CELL_VOLUME;
+        pump_moles(&mut source, &mut empty, 5.0);
+        assert_eq!(empty[Gas::BZ], 5.0);
+        assert_eq!(empty.volume, C::CELL_VOLUME);
+    }
+
+    fn soa_sample_mixtures(count: usize) -> Vec<GasMixture> {
+        (0..count)
+            .map(|i| {
+                gen_gas_mix_with_temp!(
+                    with(
+                        Gas::Pl => 50.0 + (i % 7) as f64,
+                        Gas::O2 => 100.0 + (i % 11) as f64,
+                        Gas::N2O => 20.0,
+                    )
+                    at(temperature!(500.0 + (i % 13) as f64 * 100.0, K))
+                    in(2500.0)
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn soa_react_all_test() {
+        let gms = soa_sample_mixtures(100);
+        let mut soa = GasMixtureSoA::from(gms.clone());
+
+        assert_eq!(soa.len(), gms.len());
+        assert_eq!(Vec::<GasMixture>::from(soa.clone()), gms);
+
+        soa.react_all();
+        assert_eq!(Vec::<GasMixture>::from(soa), R::react_each_once(gms));
+
+        let mut empty = GasMixtureSoA::default();
+        empty.react_all();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn soa_batch_accessors_test() {
+        let mut gms = soa_sample_mixtures(1003);
+        gms.extend(fuzz_mixtures(1003, 0x2545_f491_4f6c_dd1d));
+        let soa = GasMixtureSoA::from(gms.clone());
+
+        let heat_caps: Vec<f64> = gms.iter().map(GasMixture::get_heat_cap).collect();
+        let moles: Vec<f64> = gms.iter().map(GasMixture::get_total_amount).collect();
+        assert_eq!(soa.heat_capacities(), heat_caps);
+        assert_eq!(soa.total_moles(), moles);
+        #[cfg(feature = "simd")]
+        assert_eq!(soa.weighted_sums_scalar(Gas::specific_heat), soa.weighted_sums_simd(Gas::specific_heat));
+        assert!(GasMixtureSoA::default().heat_capacities().is_empty());
+    }
+
+    /// Timings of the batch accessors of a million-tile `GasMixtureSoA` against computing the same per `GasMixture`.
+    /// Run with `cargo test --release soa_batch_accessors_benchmark -- --ignored --nocapture`,
+    /// and with `cargo +nightly test --release --features simd ...` for the `std::simd` path.
+    #[test]
+    #[ignore]
+    fn soa_batch_accessors_benchmark() {
+        use std::hint::black_box;
+        use std::time::Instant;
+
+        let gms = soa_sample_mixtures(1_000_000);
+        let soa = GasMixtureSoA::from(gms.clone());
+
+        let best_of_five = |f: &dyn Fn() -> Vec<f64>| {
+            (0..5)
+                .map(|_| {
+                    let start = Instant::now();
+                    black_box(f());
+                    start.elapsed()
+                })
+                .min()
+                .unwrap()
+        };
+        let aos_time = best_of_five(&|| gms.iter().map(GasMixture::get_heat_cap).collect());
+        let soa_time = best_of_five(&|| soa.heat_capacities());
+        let scalar_time = best_of_five(&|| soa.weighted_sums_scalar(Gas::specific_heat));
+
+        println!(
+            "per mixture heat capacities: {:?}, GasMixtureSoA::heat_capacities: {:?}, scalar columns: {:?}",
+            aos_time, soa_time, scalar_time
         );
     }
 
+    /// Timings of `GasMixtureSoA::react_all` against `react_each_once` on a million mixtures.
+    /// Run with `cargo test --release soa_react_all_benchmark -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn soa_react_all_benchmark() {
+        use std::time::Instant;
+
+        let gms = soa_sample_mixtures(1_000_000);
+        let mut soa = GasMixtureSoA::from(gms.clone());
+
+        let start = Instant::now();
+        let reacted = R::react_each_once(gms);
+        let aos_time = start.elapsed();
+
+        let start = Instant::now();
+        soa.react_all();
+        let soa_time = start.elapsed();
+
+        assert_eq!(Vec::<GasMixture>::from(soa), reacted);
+        println!("react_each_once: {:?}, GasMixtureSoA::react_all: {:?}", aos_time, soa_time);
+    }
+
     test_reaction!(
         named(n2o_decomp_test)
         testing(R::n2o_decomp)
@@ -227,6 +3174,32 @@ mod tests {
         expect_at(temperature!(104354.42587722163, K))
     );
 
+    #[test]
+    fn stimulum_decay_test() {
+        let mix = gen_gas_mix_with_temp!(
+            with(
+                Gas::ST => 100.0,
+            )
+            at(temperature!(1000000.0, K))
+            in(2500.0)
+        );
+
+        let timeline = R::react_several(mix, 10);
+        let mut previous = mix;
+        for gm in timeline {
+            assert!(gm[Gas::ST] < previous[Gas::ST]);
+            assert!(gm.get_energy() > previous.get_energy());
+            previous = gm;
+        }
+        assert!(previous[Gas::N2] > 0.0 && previous[Gas::O2] > 0.0);
+
+        let cold = GasMixture {
+            temperature: temperature!(500.0, K),
+            ..mix
+        };
+        assert_eq!(R::stimulum_decay(cold), cold);
+    }
+
     test_reaction!(
         named(nob_synth_test)
         testing(R::hnob_synth)
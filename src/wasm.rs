@@ -0,0 +1,86 @@
+//! wasm-bindgen bindings for running the simulator in the browser, behind the `wasm` feature.
+//!
+//! Mixtures cross the boundary as plain JS objects in their versioned serde form, see `serialization`,
+//! so a mixture returned by one call can be passed straight back into the next. `summary` turns one into
+//! a `Summary`, listing only the gases present. See `examples/wasm` for a page using these bindings.
+
+use crate::gas_mixture::Summary;
+use crate::{constants as C, reactions as R, Gas, GasMixture};
+use wasm_bindgen::prelude::*;
+
+fn from_js(mixture: JsValue) -> Result<GasMixture, JsError> {
+    serde_wasm_bindgen::from_value(mixture).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Serialize maps as plain objects rather than `Map`s, so results can go straight into `JSON.stringify`.
+fn to_js<T: serde::Serialize + ?Sized>(value: &T) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Builds a mixture from gas names, starting empty at room temperature in a single tile.
+#[wasm_bindgen]
+pub struct GasMixtureBuilder {
+    moles: Vec<(Gas, f64)>,
+    temperature: f64,
+    volume: f64,
+}
+
+impl Default for GasMixtureBuilder {
+    fn default() -> Self {
+        GasMixtureBuilder {
+            moles: Vec::new(),
+            temperature: C::T20C,
+            volume: C::CELL_VOLUME,
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl GasMixtureBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `moles` of the gas called `name`, as `Gas::from_name` accepts it.
+    pub fn gas(mut self, name: &str, moles: f64) -> Result<GasMixtureBuilder, JsError> {
+        let gas = Gas::from_name(name).map_err(|e| JsError::new(&e.to_string()))?;
+        self.moles.push((gas, moles));
+        Ok(self)
+    }
+
+    pub fn temperature(mut self, kelvin: f64) -> GasMixtureBuilder {
+        self.temperature = kelvin;
+        self
+    }
+
+    pub fn volume(mut self, liters: f64) -> GasMixtureBuilder {
+        self.volume = liters;
+        self
+    }
+
+    pub fn build(&self) -> Result<JsValue, JsError> {
+        let gm = GasMixture::from_moles(&self.moles, self.temperature, self.volume)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        to_js(&gm)
+    }
+}
+
+#[wasm_bindgen]
+pub fn summary(mixture: JsValue) -> Result<JsValue, JsError> {
+    let summary: Summary = from_js(mixture)?.summary();
+    to_js(&summary)
+}
+
+#[wasm_bindgen(js_name = reactOnce)]
+pub fn react_once_js(mixture: JsValue) -> Result<JsValue, JsError> {
+    to_js(&R::react_once(from_js(mixture)?))
+}
+
+/// Every intermediate mixture of reacting `times` times, as `react_several` returns them.
+#[wasm_bindgen(js_name = reactSeveral)]
+pub fn react_several_js(mixture: JsValue, times: usize) -> Result<JsValue, JsError> {
+    to_js(&R::react_several(from_js(mixture)?, times))
+}